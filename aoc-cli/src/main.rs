@@ -0,0 +1,652 @@
+use aoc_core::prelude::*;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::{borrow::Cow, fs::File, io::BufWriter, path::PathBuf, process::ExitCode, time::Duration};
+
+/// `DAYS` of every year crate of the workspace
+const YEARS: &[&[Day]] = &[aoc2025::days::DAYS];
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Advent of Code runner",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Args, Debug)]
+struct LogArgs {
+    /// Maximum level of the log events, `RUST_LOG` takes precedence over it
+    #[arg(long, global = true, value_name = "LEVEL", default_value_t = Level::INFO)]
+    log_level: Level,
+
+    /// `target=level` filter taking precedence over the level and `RUST_LOG`, e.g.
+    /// `aoc2025::days::day04=warn`. Can be repeated
+    #[arg(long, global = true, value_name = "DIRECTIVE")]
+    log_filter: Vec<String>,
+
+    /// Write the log events to FILE, the terminal then only gets the `info` and more severe ones
+    #[arg(long, global = true, value_name = "FILE")]
+    log_file: Option<PathBuf>,
+
+    /// Start a new log file every period, its date and time are appended to the file name
+    #[arg(long, global = true, value_enum, default_value_t = LogRotationArg::Never, requires = "log_file")]
+    log_rotation: LogRotationArg,
+
+    /// Keep at most N rotated log files, the oldest ones are deleted
+    #[arg(long, global = true, value_name = "N", requires = "log_file")]
+    log_max_files: Option<usize>,
+
+    /// Colors of the terminal output
+    #[arg(long, global = true, value_enum, default_value_t = ColorArg::Auto)]
+    color: ColorArg,
+
+    /// Format of the log events
+    #[arg(long, global = true, value_enum, default_value_t = LogFormatArg::Compact)]
+    log_format: LogFormatArg,
+
+    /// Log every day, part and parse span when it closes, with its duration
+    #[arg(long, global = true)]
+    span_timings: bool,
+
+    /// Write the span stacks to FILE in the folded format of `inferno-flamegraph` and
+    /// `flamegraph.pl`
+    #[arg(long, global = true, value_name = "FILE")]
+    flame: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum LogFormatArg {
+    /// Human readable, one line per event
+    Compact,
+    /// One JSON object per event
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum LogRotationArg {
+    Never,
+    Minutely,
+    Hourly,
+    Daily,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ColorArg {
+    /// Only when the output is a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+impl LogArgs {
+    fn config(&self) -> TracingConfig {
+        let default = TracingConfig::default();
+
+        let rotation = match self.log_rotation {
+            LogRotationArg::Never => LogRotation::Never,
+            LogRotationArg::Minutely => LogRotation::Minutely,
+            LogRotationArg::Hourly => LogRotation::Hourly,
+            LogRotationArg::Daily => LogRotation::Daily,
+        };
+
+        return TracingConfig {
+            level: self.log_level,
+            filters: self.log_filter.clone(),
+            ansi: match self.color {
+                ColorArg::Auto => default.ansi,
+                ColorArg::Always => true,
+                ColorArg::Never => false,
+            },
+            format: match self.log_format {
+                LogFormatArg::Compact => LogFormat::Compact,
+                LogFormatArg::Json => LogFormat::Json,
+            },
+            span_timings: self.span_timings,
+            log_file: self.log_file.as_ref().map(|path| LogFile {
+                path: path.clone(),
+                rotation,
+                max_files: self.log_max_files,
+            }),
+            flame: self.flame.clone(),
+        };
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the selected days (default when no subcommand is given)
+    Run(RunArgs),
+    /// Check the selected days against the known answers file
+    Verify(VerifyArgs),
+    /// Generate the module, inputs and registration of a new day
+    New(NewArgs),
+    /// Download the input and the example of a day into the inputs directory
+    Fetch(FetchArgs),
+    /// Solve a part and submit the answer, unless the submission history rules it out
+    Submit(SubmitArgs),
+    /// Serve fixture puzzles locally, point `base_url` at it to fetch without network
+    #[cfg(feature = "mock-server")]
+    MockServer(MockServerArgs),
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Days to run: `2025/5`, `2025/1..=5`, `2025/1..5`, `2025/all` or `all`. Without a year,
+    /// the days of the latest year
+    #[arg(default_value = "all")]
+    days: Vec<DaySelection>,
+
+    /// Only run the given part (1 or 2)
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Directory containing the `<year>_<day>_input.txt` files
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Input file to use instead of the inputs directory (single day only)
+    #[arg(long, value_name = "FILE", conflicts_with = "input_dir")]
+    input: Option<PathBuf>,
+
+    /// Run each day this many times and report min/median/mean timings
+    #[arg(long, value_name = "RUNS", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Export answers and timings as JSON to this file
+    #[arg(long, value_name = "FILE")]
+    json: Option<PathBuf>,
+
+    /// Show the simulation of the days that have one, in the terminal or as images
+    #[arg(
+        long,
+        value_name = "MODE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "terminal"
+    )]
+    visualize: Option<VisualizeMode>,
+
+    /// Delay between two visualization frames, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 100)]
+    frame_delay: u64,
+
+    /// Side of a grid cell in the exported images, in pixels
+    #[arg(long, value_name = "PX", default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..))]
+    cell_size: u16,
+
+    /// Directory the visualization images are written to
+    #[arg(long, value_name = "DIR", default_value = "visualize")]
+    visualize_dir: PathBuf,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum VisualizeMode {
+    /// Play the frames in the terminal
+    Terminal,
+    /// One PPM image per frame
+    Ppm,
+    /// One PNG image per frame
+    Png,
+    /// A single animated GIF
+    Gif,
+}
+
+#[derive(Args, Debug)]
+struct VerifyArgs {
+    /// Days to verify: `2025/5`, `2025/1..=5`, `2025/1..5`, `2025/all` or `all`. Without a
+    /// year, the days of the latest year
+    #[arg(default_value = "all")]
+    days: Vec<DaySelection>,
+
+    /// Directory containing the `<year>_<day>_input.txt` files
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Known answers file, defaults to `answers.toml` in the workspace
+    #[arg(long, value_name = "FILE")]
+    answers: Option<PathBuf>,
+
+    /// Record answers that are still missing instead of only reporting them
+    #[arg(long)]
+    record: bool,
+
+    /// Check the `<year>_<day>_input_test.txt` examples against the `example` answers instead
+    #[arg(long)]
+    example: bool,
+}
+
+#[derive(Args, Debug)]
+struct NewArgs {
+    /// Day to create, from 1 to 25
+    day: u8,
+
+    /// Year of the day, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Crate to generate the day in, defaults to the `aoc<year>` crate of the workspace
+    #[arg(long, value_name = "DIR")]
+    crate_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct FetchArgs {
+    /// Day to fetch, from 1 to 25
+    day: u8,
+
+    /// Year of the day, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Directory to write the `<year>_<day>_input.txt` files to
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// Download again even when the responses are cached
+    #[arg(long)]
+    refresh: bool,
+
+    /// Replace input files that have other contents
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args, Debug)]
+struct SubmitArgs {
+    /// Day to submit, from 1 to 25
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    part: Part,
+
+    /// Year of the day, defaults to the latest registered year
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Answer to submit instead of solving the registered day
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Directory containing the `<year>_<day>_input.txt` files
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Submission history file, defaults to `submissions.toml` in the workspace
+    #[arg(long, value_name = "FILE")]
+    history: Option<PathBuf>,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+/// Puzzle server settings shared by the commands that reach it
+#[derive(Args, Debug)]
+struct ServerArgs {
+    /// Config file with the session token, defaults to `aoc.toml` in the workspace
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Puzzle server to use instead of the configured one
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+impl ServerArgs {
+    fn client(self) -> Result<Client> {
+        let mut config = Config::load(Config::path(self.config))?;
+
+        if let Some(base_url) = self.base_url {
+            config.base_url = Some(base_url);
+        }
+
+        return Ok(Client::new(config));
+    }
+}
+
+#[cfg(feature = "mock-server")]
+#[derive(Args, Debug)]
+struct MockServerArgs {
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+    addr: String,
+
+    /// Directory with the `<year>_<day>_input.txt` and `<year>_<day>_puzzle.html` files and
+    /// the `answers.toml` that answers are judged against
+    #[arg(long, value_name = "DIR", default_value = MOCK_FIXTURES_DIR)]
+    fixtures: PathBuf,
+
+    /// Session token the server accepts
+    #[arg(long, default_value = MOCK_SESSION)]
+    session: String,
+
+    /// Seconds to wait after a wrong answer before the next one is judged
+    #[arg(long, value_name = "SECS", default_value_t = 60)]
+    cooldown: u64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Kept until the end so that the log and flame graph files are flushed
+    let _guard = match init_tracing(&cli.log.config()) {
+        Ok(guard) => guard,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = Registry::new(YEARS).and_then(|registry| match cli.command {
+        Some(Command::Run(args)) => run(&registry, args),
+        Some(Command::Verify(args)) => verify(&registry, args),
+        Some(Command::New(args)) => new(&registry, args),
+        Some(Command::Fetch(args)) => fetch(&registry, args),
+        Some(Command::Submit(args)) => submit(&registry, args),
+        #[cfg(feature = "mock-server")]
+        Some(Command::MockServer(args)) => mock_server(args),
+        None => run(&registry, cli.run),
+    });
+
+    if let Err(error) = result {
+        error!("{error}");
+        return ExitCode::FAILURE;
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+fn select_days(registry: &Registry, selections: &[DaySelection]) -> Result<Vec<&'static Day>> {
+    let days = registry.select(selections);
+
+    if days.is_empty() {
+        return Err("No registered day matches the selection".into());
+    }
+
+    return Ok(days);
+}
+
+fn run(registry: &Registry, args: RunArgs) -> Result {
+    let days = select_days(registry, &args.days)?;
+
+    if args.input.is_some() && days.len() > 1 {
+        return Err("--input can only be used when a single day is selected".into());
+    }
+
+    let loader = InputLoader::new(args.input_dir.clone());
+    let mut exports = vec![];
+
+    for day in days {
+        let _span = info_span!("day", year = day.year, day = day.day).entered();
+
+        let (source_name, text_input) = match &args.input {
+            Some(path) => (path.display().to_string(), load_file(path)?.into()),
+            None => (loader.source_name(day), loader.load(day)?),
+        };
+        let with_source = |error: AocError| error.with_source(&source_name, &text_input);
+
+        if let Some(runs) = args.bench {
+            let day_timings = bench(day, &text_input, args.part, runs as _).map_err(with_source)?;
+
+            info!("{}", day_timings.answers);
+            info!("{day_timings}");
+            exports.push(day_timings);
+        } else {
+            let (report, timings) = (day.run)(&text_input, args.part).map_err(with_source)?;
+
+            info!("{report}");
+            info!("{timings}");
+            exports.extend(DayTimings::new(day, report, &[timings]));
+        }
+
+        if let Some(mode) = args.visualize {
+            visualize(day, &text_input, mode, &args).map_err(with_source)?;
+        }
+    }
+
+    if let Some(path) = args.json {
+        std::fs::write(&path, serde_json::to_string_pretty(&exports)?)?;
+        info!("Timings exported to {}", path.display());
+    }
+
+    return Ok(());
+}
+
+fn visualize(day: &Day, text_input: &str, mode: VisualizeMode, args: &RunArgs) -> Result {
+    let Some(frames) = (day.visualize)(text_input)? else {
+        warn!("Nothing to visualize");
+        return Ok(());
+    };
+
+    let delay = Duration::from_millis(args.frame_delay);
+    let cell_size = args.cell_size as _;
+    let stem = format!("{}_{:02}", day.year, day.day);
+
+    let format = match mode {
+        VisualizeMode::Terminal => return play(&frames, delay, &mut std::io::stdout().lock()),
+        VisualizeMode::Ppm => ImageFormat::Ppm,
+        VisualizeMode::Png => ImageFormat::Png,
+        VisualizeMode::Gif => {
+            std::fs::create_dir_all(&args.visualize_dir)?;
+
+            let path = args.visualize_dir.join(format!("{stem}.gif"));
+            write_gif(
+                &frames,
+                cell_size,
+                delay,
+                BufWriter::new(File::create(&path)?),
+            )?;
+            info!("{} frames written to {}", frames.len(), path.display());
+
+            return Ok(());
+        }
+    };
+
+    let paths = write_images(&frames, format, cell_size, &args.visualize_dir, &stem)?;
+    info!(
+        "{} frames written to {}",
+        paths.len(),
+        args.visualize_dir.display()
+    );
+
+    return Ok(());
+}
+
+fn verify(registry: &Registry, args: VerifyArgs) -> Result {
+    let days = select_days(registry, &args.days)?;
+    let loader = InputLoader::new(args.input_dir.clone());
+    let answers_path = AnswerStore::path(args.answers);
+    let mut store = AnswerStore::load(&answers_path)?;
+    let mut checked = match args.example {
+        true => store.examples(),
+        false => store.clone(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let _span = info_span!("day", year = day.year, day = day.day).entered();
+
+        let (text_input, source_name) = match args.example {
+            true => (
+                loader.load_example(day).map(Cow::Owned),
+                loader.example_path(day.year, day.day).display().to_string(),
+            ),
+            false => (loader.load(day), loader.source_name(day)),
+        };
+        let text_input = match text_input {
+            Ok(text_input) => text_input,
+            Err(error) => {
+                warn!("NO INPUT: {error}");
+                missing += Part::ALL.len();
+                continue;
+            }
+        };
+
+        let report = match (day.run)(&text_input, None) {
+            Ok((report, _)) => report,
+            Err(error) => {
+                error!("FAIL, {}", error.with_source(source_name, &text_input));
+                failed += Part::ALL.len();
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let Some(actual) = report.get(part) else {
+                continue;
+            };
+
+            match checked.verify(day.year, day.day, part, actual) {
+                Ok(Verdict::Pass) => {
+                    info!("part {part}: PASS ({actual})");
+                    passed += 1;
+                }
+                Err(error) => {
+                    error!("part {part}: FAIL, {error}");
+                    failed += 1;
+                }
+                Ok(Verdict::Missing) if args.record => {
+                    info!("part {part}: RECORDED ({actual})");
+                    checked
+                        .get_mut(day.year, day.day)
+                        .set(part, Some(actual.to_owned()));
+                    passed += 1;
+                }
+                Ok(Verdict::Missing) => {
+                    warn!("part {part}: MISSING ({actual})");
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    if args.record {
+        match args.example {
+            true => store.set_examples(checked),
+            false => store = checked,
+        }
+        store.save(&answers_path)?;
+    }
+
+    info!("Verify: {passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        return Err(format!("{failed} answer(s) failed").into());
+    }
+
+    return Ok(());
+}
+
+/// `year`, or the latest registered year
+fn year_or_latest(registry: &Registry, year: Option<u16>) -> Result<u16> {
+    year.or_else(|| registry.latest_year())
+        .ok_or_else(|| "No registered year, pass --year".into())
+}
+
+fn new(registry: &Registry, args: NewArgs) -> Result {
+    let year = year_or_latest(registry, args.year)?;
+    let crate_dir = args.crate_dir.unwrap_or_else(|| year_dir(year));
+
+    for path in scaffold_day(&crate_dir, year, args.day)? {
+        info!("Wrote {}", path.display());
+    }
+
+    return Ok(());
+}
+
+fn fetch(registry: &Registry, args: FetchArgs) -> Result {
+    let year = year_or_latest(registry, args.year)?;
+    let client = args.server.client()?.with_refresh(args.refresh);
+    let dir = InputLoader::new(args.input_dir).resolved_dir(year);
+
+    let written = client.fetch_into(&dir, year, args.day, args.force)?;
+    for path in &written {
+        info!("Wrote {}", path.display());
+    }
+
+    if written.is_empty() {
+        info!("Inputs of {year} day {} are up to date", args.day);
+    }
+
+    return Ok(());
+}
+
+fn submit(registry: &Registry, args: SubmitArgs) -> Result {
+    let year = year_or_latest(registry, args.year)?;
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let Some(day) = registry.get(year, args.day) else {
+                return Err(format!("Day {year}/{} is not registered", args.day).into());
+            };
+
+            let loader = InputLoader::new(args.input_dir);
+            let text_input = loader.load(day)?;
+            let (report, _) = (day.run)(&text_input, Some(args.part))
+                .map_err(|error| error.with_source(loader.source_name(day), &text_input))?;
+
+            report
+                .get(args.part)
+                .expect("The selected part was run")
+                .to_owned()
+        }
+    };
+
+    let client = args.server.client()?;
+    let history_path = History::path(args.history);
+    let mut history = History::load(&history_path)?;
+
+    let response = history.submit(&client, year, args.day, args.part, &answer);
+    history.save(&history_path)?;
+    let SubmitResponse { outcome, wait } = response?;
+
+    match outcome {
+        Outcome::Correct => info!(
+            "{year} day {} part {}: {answer} is correct",
+            args.day, args.part
+        ),
+        _ => warn!(
+            "{year} day {} part {}: {answer} is {outcome}",
+            args.day, args.part
+        ),
+    }
+
+    if let Some(wait) = wait {
+        info!("Next answer accepted in {}s", wait.as_secs());
+    }
+
+    return Ok(());
+}
+
+#[cfg(feature = "mock-server")]
+fn mock_server(args: MockServerArgs) -> Result {
+    let server = MockServer::start(
+        &args.addr,
+        MockPuzzles {
+            fixtures: args.fixtures.clone(),
+            session: args.session,
+            cooldown: Duration::from_secs(args.cooldown),
+        },
+    )?;
+    info!(
+        "Serving {} on {}, stop with Ctrl-C",
+        args.fixtures.display(),
+        server.url()
+    );
+
+    server.wait();
+
+    return Ok(());
+}
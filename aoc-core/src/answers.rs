@@ -0,0 +1,155 @@
+use crate::prelude::*;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding [`DEFAULT_ANSWERS_FILE`]
+pub const ANSWERS_FILE_ENV: &str = "AOC_ANSWERS_FILE";
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Known answers keyed by year, then day. The answers of the examples are under `example`:
+///
+/// ```toml
+/// [2025.5]
+/// part_1 = "3"
+/// part_2 = "14"
+///
+/// [example.2025.5]
+/// part_1 = "3"
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct AnswerStore {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub example: BTreeMap<String, BTreeMap<String, Report>>,
+    #[serde(flatten)]
+    pub years: BTreeMap<String, BTreeMap<String, Report>>,
+}
+
+impl AnswerStore {
+    pub fn path(path: Option<PathBuf>) -> PathBuf {
+        path.or_else(|| std::env::var_os(ANSWERS_FILE_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE))
+    }
+
+    /// A missing file is an empty store, every answer is then reported as missing
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            warn!("Answers file {} does not exist yet", path.display());
+            return Ok(Self::default());
+        }
+
+        let text = std::fs::read_to_string(path)?;
+
+        return Ok(toml::from_str(&text)?);
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result {
+        std::fs::write(path, toml::to_string(self)?)?;
+        return Ok(());
+    }
+
+    /// The answers of the examples as a store of their own, see [`AnswerStore::set_examples`]
+    pub fn examples(&self) -> Self {
+        Self {
+            example: BTreeMap::new(),
+            years: self.example.clone(),
+        }
+    }
+
+    pub fn set_examples(&mut self, examples: Self) { self.example = examples.years; }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Report> {
+        self.years.get(&year.to_string())?.get(&day.to_string())
+    }
+
+    pub fn get_mut(&mut self, year: u16, day: u8) -> &mut Report {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+    }
+
+    /// A known answer that differs from `actual` is an [`AocError::WrongAnswer`]
+    pub fn verify(&self, year: u16, day: u8, part: Part, actual: &str) -> Result<Verdict> {
+        match self.get(year, day).and_then(|answers| answers.get(part)) {
+            Some(expected) if expected == actual => Ok(Verdict::Pass),
+            Some(expected) => Err(AocError::WrongAnswer {
+                year,
+                day,
+                part,
+                expected: expected.to_owned(),
+                actual: actual.to_owned(),
+            }),
+            None => Ok(Verdict::Missing),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Display)]
+pub enum Verdict {
+    #[display("PASS")]
+    Pass,
+    #[display("MISSING")]
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[2025.5]
+part_1 = "3"
+part_2 = "14"
+
+[2025.4]
+part_1 = "13"
+
+[example.2025.4]
+part_1 = "13"
+part_2 = "43"
+"#;
+
+    #[test]
+    fn verify() -> Result {
+        let store: AnswerStore = toml::from_str(ANSWERS)?;
+
+        assert_eq!(store.verify(2025, 5, Part::One, "3")?, Verdict::Pass);
+        assert!(matches!(
+            store.verify(2025, 5, Part::Two, "15"),
+            Err(AocError::WrongAnswer { expected, actual, .. }) if expected == "14" && actual == "15"
+        ));
+        assert_eq!(store.verify(2025, 4, Part::Two, "43")?, Verdict::Missing);
+        assert_eq!(store.verify(2024, 1, Part::One, "1")?, Verdict::Missing);
+        assert_eq!(
+            store.examples().verify(2025, 4, Part::Two, "43")?,
+            Verdict::Pass
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn roundtrip() -> Result {
+        let mut store = AnswerStore::default();
+        store.get_mut(2025, 1).set(Part::Two, Some("6".into()));
+        let mut examples = store.examples();
+        examples.get_mut(2025, 1).set(Part::One, Some("3".into()));
+        store.set_examples(examples);
+
+        let reloaded: AnswerStore = toml::from_str(&toml::to_string(&store)?)?;
+        assert_eq!(reloaded, store);
+        assert_eq!(
+            reloaded.get(2025, 1).and_then(|r| r.get(Part::Two)),
+            Some("6")
+        );
+
+        return Ok(());
+    }
+}
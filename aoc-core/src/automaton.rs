@@ -0,0 +1,171 @@
+use crate::prelude::*;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Cellular automaton over a [`Grid`], `rule` gives the next state of a cell or `None` to
+/// keep it. Returning the current state is not counted as a change
+#[derive(Clone, Debug)]
+pub struct Automaton<T, R> {
+    grid: Grid<T>,
+    rule: R,
+    /// Cells whose state the rule reads, re-examined by [`Automaton::run_async`] on a change
+    neighbors: &'static [(isize, isize)],
+}
+
+/// Changes made until reaching a fixed point
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Run {
+    /// Changed cells per step, the last step is the first one without changes
+    pub changes: Vec<usize>,
+}
+
+impl Run {
+    pub fn total(&self) -> usize { self.changes.iter().sum() }
+
+    /// Steps that changed at least one cell
+    pub fn steps(&self) -> usize { self.changes.len().saturating_sub(1) }
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq + Send + Sync,
+    R: Fn(&Grid<T>, Pos) -> Option<T> + Sync,
+{
+    /// Rule reading the 8 neighbors of a cell, see [`Automaton::with_neighbors`] otherwise
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            grid,
+            rule,
+            neighbors: &NEIGHBORS_8,
+        }
+    }
+
+    pub fn with_neighbors(self, neighbors: &'static [(isize, isize)]) -> Self {
+        Self { neighbors, ..self }
+    }
+
+    pub fn grid(&self) -> &Grid<T> { &self.grid }
+
+    pub fn into_grid(self) -> Grid<T> { self.grid }
+
+    fn next_state(&self, pos: Pos) -> Option<T> {
+        (self.rule)(&self.grid, pos).filter(|next| *next != self.grid[pos])
+    }
+
+    /// Synchronous step, every cell reads the previous generation. Returns the changed cells
+    pub fn step(&mut self) -> Vec<Pos> {
+        let changes = (0..self.grid.len())
+            .into_par_iter()
+            .map(|index| self.grid.pos_of(index))
+            .filter_map(|pos| Some((pos, self.next_state(pos)?)))
+            .collect::<Vec<_>>();
+
+        return changes
+            .into_iter()
+            .map(|(pos, next)| {
+                self.grid[pos] = next;
+                pos
+            })
+            .collect();
+    }
+
+    /// Synchronous steps until nothing changes
+    pub fn run_sync(&mut self) -> Run { self.run_sync_observed(|_, _| {}) }
+
+    /// [`Automaton::run_sync`], calling `observe` with the grid and the changed cells after
+    /// every step, the last one included
+    pub fn run_sync_observed(&mut self, mut observe: impl FnMut(&Grid<T>, &[Pos])) -> Run {
+        let mut run = Run::default();
+
+        loop {
+            let changed = self.step();
+            run.changes.push(changed.len());
+            debug!("step {}: {} changes", run.changes.len(), changed.len());
+
+            observe(&self.grid, &changed);
+
+            if changed.is_empty() {
+                return run;
+            }
+        }
+    }
+
+    /// Asynchronous propagation until nothing changes: changes apply immediately and each step
+    /// only re-examines the changed cells and their neighbors. Reaches the same fixed point as
+    /// [`Automaton::run_sync`] for rules where the update order does not matter
+    pub fn run_async(&mut self) -> Run {
+        let mut run = Run::default();
+        let mut worklist = self.grid.positions().collect::<Vec<_>>();
+        let mut queued = Grid::new(self.grid.width(), self.grid.height(), false);
+
+        loop {
+            let mut changed = vec![];
+
+            for pos in worklist.drain(..) {
+                queued[pos] = false;
+
+                if let Some(next) = self.next_state(pos) {
+                    self.grid[pos] = next;
+                    changed.push(pos);
+                }
+            }
+
+            run.changes.push(changed.len());
+            debug!("step {}: {} changes", run.changes.len(), changed.len());
+
+            if changed.is_empty() {
+                return run;
+            }
+
+            for pos in changed {
+                for pos in std::iter::once(pos).chain(self.grid.neighbors(pos, self.neighbors)) {
+                    if !queued[pos] {
+                        queued[pos] = true;
+                        worklist.push(pos);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fills every empty cell next to a filled one
+    fn flood(grid: &Grid<bool>, pos: Pos) -> Option<bool> {
+        (!grid[pos] && grid.neighbors_4(pos).any(|neighbor| grid[neighbor])).then_some(true)
+    }
+
+    fn seeded() -> Grid<bool> {
+        let mut grid = Grid::new(5, 3, false);
+        grid[(0, 0)] = true;
+
+        return grid;
+    }
+
+    #[test]
+    fn sync_steps() {
+        let mut automaton = Automaton::new(seeded(), flood).with_neighbors(&NEIGHBORS_4);
+
+        assert_eq!(automaton.step(), [(1, 0), (0, 1)]);
+
+        let run = automaton.run_sync();
+        assert_eq!(run.changes, [3, 3, 3, 2, 1, 0]);
+        assert_eq!(run.steps(), 5);
+        assert!(automaton.grid().cells().iter().all(|filled| *filled));
+    }
+
+    #[test]
+    fn async_reaches_same_fixed_point() {
+        let mut sync = Automaton::new(seeded(), flood).with_neighbors(&NEIGHBORS_4);
+        let mut propagated = sync.clone();
+
+        let sync_run = sync.run_sync();
+        let async_run = propagated.run_async();
+
+        assert_eq!(sync.grid(), propagated.grid());
+        assert_eq!(async_run.total(), sync_run.total());
+        assert_eq!(async_run.changes.last(), Some(&0));
+    }
+}
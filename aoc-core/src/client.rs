@@ -0,0 +1,335 @@
+use crate::prelude::*;
+use serde::Deserialize;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Environment variable holding the session cookie, takes precedence over [`Config::session`]
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable overriding [`Config::base_url`]
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable overriding [`DEFAULT_CONFIG_FILE`]
+pub const CONFIG_FILE_ENV: &str = "AOC_CONFIG_FILE";
+pub const DEFAULT_CONFIG_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc-cache");
+
+/// Puzzle server settings, every field is optional:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://127.0.0.1:8080"
+/// cache_dir = "/tmp/aoc-cache"
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn path(path: Option<PathBuf>) -> PathBuf {
+        path.or_else(|| std::env::var_os(CONFIG_FILE_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CONFIG_FILE))
+    }
+
+    /// A missing file is an empty config, [`SESSION_ENV`] and [`BASE_URL_ENV`] are applied on
+    /// top of it
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let mut config = match path.exists() {
+            true => toml::from_str(&std::fs::read_to_string(path)?)?,
+            false => Self::default(),
+        };
+
+        if let Ok(session) = std::env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            config.base_url = Some(base_url);
+        }
+
+        return Ok(config);
+    }
+}
+
+/// Client of the puzzle website, responses are cached under `<cache_dir>/<year>/<day>`
+#[derive(Debug)]
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    /// Ignore the cached responses, they are still updated
+    refresh: bool,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(concat!(env!("CARGO_PKG_REPOSITORY"), " (ureq)"))
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        return Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: config.session.map(|session| session.trim().to_owned()),
+            cache_dir: config
+                .cache_dir
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_DIR)),
+            refresh: false,
+        };
+    }
+
+    pub fn with_refresh(self, refresh: bool) -> Self { Self { refresh, ..self } }
+
+    pub fn url(&self, path: &str) -> String { format!("{}{path}", self.base_url) }
+
+    fn cookie(&self) -> Result<String> {
+        let session = self.session.as_ref().ok_or(AocError::MissingSession)?;
+        return Ok(format!("session={session}"));
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = self.url(path);
+        debug!("GET {url}");
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie()?)
+            .call()?;
+
+        return read_body(url, response);
+    }
+
+    /// Reads `<cache_dir>/<year>/<day>/<name>`, fetching and storing it when missing
+    fn cached(
+        &self,
+        year: u16,
+        day: u8,
+        name: &str,
+        fetch: impl FnOnce() -> Result<String>,
+    ) -> Result<String> {
+        let dir = self
+            .cache_dir
+            .join(year.to_string())
+            .join(format!("{day:02}"));
+        let path = dir.join(name);
+
+        if !self.refresh && path.is_file() {
+            debug!("Using cached {}", path.display());
+            return Ok(std::fs::read_to_string(path)?);
+        }
+
+        let body = fetch()?;
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(path, &body)?;
+
+        return Ok(body);
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        self.cached(year, day, "input.txt", || {
+            self.get(&format!("/{year}/day/{day}/input"))
+        })
+    }
+
+    /// Puzzle description, it only has the second part once the first one is solved
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        self.cached(year, day, "puzzle.html", || {
+            self.get(&format!("/{year}/day/{day}"))
+        })
+    }
+
+    /// Example of the puzzle description, see [`extract_example`]
+    pub fn example(&self, year: u16, day: u8) -> Result<Option<String>> {
+        Ok(extract_example(&self.puzzle(year, day)?))
+    }
+
+    /// Posts `answer` to the answer page of the `part`, never cached. See [`History::submit`] to
+    /// rule out known wrong answers first
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<SubmitResponse> {
+        let url = self.url(&format!("/{year}/day/{day}/answer"));
+        debug!("POST {url}");
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie()?)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        return SubmitResponse::parse(&read_body(url, response)?);
+    }
+
+    /// Writes the input and the example of `day` to `dir`, returns the written paths.
+    /// Existing non-empty files are only replaced with `force`
+    pub fn fetch_into(&self, dir: &Path, year: u16, day: u8, force: bool) -> Result<Vec<PathBuf>> {
+        let mut written = vec![];
+        std::fs::create_dir_all(dir)?;
+
+        let input = dir.join(input_file_name(year, day));
+        if write_unless_filled(&input, &self.input(year, day)?, force)? {
+            written.push(input);
+        }
+
+        let example = dir.join(example_file_name(year, day));
+        match self.example(year, day)? {
+            Some(text) => {
+                if write_unless_filled(&example, &text, force)? {
+                    written.push(example);
+                }
+            }
+            None => warn!("No example found in the puzzle description of {year} day {day}"),
+        }
+
+        return Ok(written);
+    }
+}
+
+fn read_body(url: String, response: ureq::http::Response<ureq::Body>) -> Result<String> {
+    let status = response.status();
+
+    if !status.is_success() {
+        return Err(AocError::HttpStatus {
+            url,
+            status: status.as_u16(),
+        });
+    }
+
+    return Ok(response.into_body().read_to_string()?);
+}
+
+/// Returns whether `path` was written, files with other contents are kept unless `force`
+fn write_unless_filled(path: &Path, contents: &str, force: bool) -> Result<bool> {
+    let existing = std::fs::read_to_string(path).unwrap_or_default();
+
+    if existing == contents {
+        debug!("{} is up to date", path.display());
+        return Ok(false);
+    }
+
+    if !existing.is_empty() && !force {
+        warn!(
+            "{} differs, keeping it (use --force to replace it)",
+            path.display()
+        );
+        return Ok(false);
+    }
+
+    std::fs::write(path, contents)?;
+
+    return Ok(true);
+}
+
+/// Text of the first `<pre><code>` block of a puzzle description, usually the example input
+pub fn extract_example(html: &str) -> Option<String> {
+    const OPEN: &str = "<pre><code>";
+
+    let start = html.find(OPEN)? + OPEN.len();
+    let end = start + html[start..].find("</code></pre>")?;
+
+    return Some(html_text(&html[start..end]));
+}
+
+/// `html` without its tags and with the usual entities decoded
+pub fn html_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    // `&amp;` last so that an escaped entity is not decoded twice
+    return text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_extraction() {
+        let html = "<p>For example:</p>\n<pre><code>a &lt;<em>b</em>&gt; &amp;lt;\n</code></pre>\
+                    <pre><code>second</code></pre>";
+
+        assert_eq!(extract_example(html).as_deref(), Some("a <b> &lt;\n"));
+        assert_eq!(extract_example("<p>No code</p>"), None);
+    }
+
+    #[test]
+    fn fetches_through_cache() -> Result {
+        let server = MockServer::start("127.0.0.1:0", MockPuzzles::default())?;
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let config = |session: Option<&str>| Config {
+            session: session.map(str::to_owned),
+            base_url: Some(server.url().to_owned()),
+            cache_dir: Some(dir.join("cache")),
+        };
+
+        let client = Client::new(config(Some(MOCK_SESSION)));
+        let inputs = dir.join("inputs");
+
+        let written = client.fetch_into(&inputs, 2025, 1, false)?;
+        assert_eq!(written.len(), 2);
+        assert_eq!(server.requests(), 2);
+        assert_eq!(
+            std::fs::read_to_string(inputs.join(input_file_name(2025, 1)))?,
+            std::fs::read_to_string(Path::new(MOCK_FIXTURES_DIR).join("2025_01_input.txt"))?,
+        );
+        assert!(
+            std::fs::read_to_string(inputs.join(example_file_name(2025, 1)))?.starts_with("L68\n")
+        );
+
+        // Served from the cache: the input is up to date, and the edited example is only
+        // replaced with `force`
+        let example = inputs.join(example_file_name(2025, 1));
+        std::fs::write(&example, "edited")?;
+        assert!(client.fetch_into(&inputs, 2025, 1, false)?.is_empty());
+        assert_eq!(std::fs::read_to_string(&example)?, "edited");
+        assert_eq!(
+            client.fetch_into(&inputs, 2025, 1, true)?,
+            [example.as_path()]
+        );
+        assert!(std::fs::read_to_string(&example)?.starts_with("L68\n"));
+        assert_eq!(server.requests(), 2);
+
+        assert!(matches!(
+            Client::new(config(Some("wrong"))).input(2025, 2),
+            Err(AocError::HttpStatus { status: 400, .. })
+        ));
+        assert!(matches!(
+            client.input(2025, 2),
+            Err(AocError::HttpStatus { status: 404, .. })
+        ));
+        assert!(matches!(
+            Client::new(config(None)).input(2025, 2),
+            Err(AocError::MissingSession)
+        ));
+
+        std::fs::remove_dir_all(dir)?;
+
+        return Ok(());
+    }
+}
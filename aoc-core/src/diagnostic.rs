@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+/// Parse error rendered against its input like a rustc diagnostic:
+///
+/// ```text
+/// Parse error: Unknown direction `Q`, expected `L` or `R`
+///  --> inputs/2025_01_input.txt:2:1
+///   |
+/// 2 | Q3
+///   | ^
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Diagnostic {
+    pub source_name: String,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in bytes
+    pub column: usize,
+    /// Underlined width, in bytes
+    pub len: usize,
+    pub message: String,
+    /// The offending line, `None` when the location is past the end of the input
+    pub line_text: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(
+        source_name: impl Display,
+        source: &str,
+        line: usize,
+        column: usize,
+        len: usize,
+        message: impl Display,
+    ) -> Self {
+        Self {
+            source_name: source_name.to_string(),
+            line,
+            column,
+            len,
+            message: message.to_string(),
+            line_text: line
+                .checked_sub(1)
+                .and_then(|i| source.lines().nth(i))
+                .map(str::to_owned),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            source_name,
+            line,
+            column,
+            len,
+            message,
+            line_text,
+        } = self;
+
+        let gutter = " ".repeat(line.to_string().len());
+
+        writeln!(f, "Parse error: {message}")?;
+        write!(f, "{gutter}--> {source_name}:{line}:{column}")?;
+
+        let Some(line_text) = line_text else {
+            return Ok(());
+        };
+
+        // Columns are byte offsets, the caret is padded by the characters before it
+        let byte_start = column.saturating_sub(1).min(line_text.len());
+        let byte_end = (byte_start + len).min(line_text.len());
+        let chars = |bytes: &[u8]| String::from_utf8_lossy(bytes).chars().count();
+
+        let padding = chars(&line_text.as_bytes()[..byte_start]);
+        let width = chars(&line_text.as_bytes()[byte_start..byte_end]).max(1);
+
+        writeln!(f)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {line_text}")?;
+        write!(f, "{gutter} | {}{}", " ".repeat(padding), "^".repeat(width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let diagnostic = Diagnostic::new(
+            "input.txt",
+            "L68\nR4x\n",
+            2,
+            2,
+            2,
+            "invalid digit found in string",
+        );
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "Parse error: invalid digit found in string\n \
+             --> input.txt:2:2\n  \
+              |\n\
+             2 | R4x\n  \
+              |  ^^"
+        );
+    }
+
+    #[test]
+    fn render_past_end() {
+        let diagnostic = Diagnostic::new("input.txt", "3-5", 2, 1, 1, "Missing empty line");
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "Parse error: Missing empty line\n --> input.txt:2:1"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+/// Most digits a [`Subsequence`] can hold, `10^38 - 1` is the largest all-nines `u128`
+pub const MAX_SUBSEQUENCE_DIGITS: usize = 38;
+
+/// Outcome of [`max_subsequence_number`]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Subsequence {
+    pub value: u128,
+    /// Indices of the picked digits, ascending
+    pub indices: Vec<usize>,
+}
+
+/// Largest number made of `k` digits of `digits` kept in their order, `digits` holding values
+/// in `0..=9`. `None` when there are fewer than `k` digits or `k` is over
+/// [`MAX_SUBSEQUENCE_DIGITS`].
+///
+/// Greedy monotonic stack in O(n): a digit pops the smaller digits before it while enough
+/// digits are left to still pick `k`
+pub fn max_subsequence_number(digits: &[u8], k: usize) -> Option<Subsequence> {
+    if k > digits.len() || k > MAX_SUBSEQUENCE_DIGITS {
+        return None;
+    }
+
+    let mut droppable = digits.len() - k;
+    let mut stack = Vec::<usize>::with_capacity(digits.len());
+
+    for (i, digit) in digits.iter().enumerate() {
+        debug_assert!(*digit <= 9, "Digit {digit} at {i} should be in 0..=9");
+
+        while droppable > 0
+            && let Some(top) = stack.last()
+            && digits[*top] < *digit
+        {
+            stack.pop();
+            droppable -= 1;
+        }
+
+        stack.push(i);
+    }
+
+    // Whatever could not be dropped on the way is the smallest tail
+    stack.truncate(k);
+
+    let value = stack
+        .iter()
+        .fold(0, |value, i| value * 10 + digits[*i] as u128);
+
+    return Some(Subsequence {
+        value,
+        indices: stack,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Vec<u8> { text.bytes().map(|byte| byte - b'0').collect() }
+
+    #[test]
+    fn picks_largest() {
+        let Subsequence { value, indices } =
+            max_subsequence_number(&digits("818181911112111"), 2).unwrap();
+
+        assert_eq!(value, 92);
+        assert_eq!(indices, [6, 11]);
+
+        assert_eq!(
+            max_subsequence_number(&digits("4321"), 2).unwrap().value,
+            43
+        );
+        assert_eq!(
+            max_subsequence_number(&digits("1234"), 2).unwrap().value,
+            34
+        );
+        assert_eq!(max_subsequence_number(&digits("1234"), 0).unwrap().value, 0);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(max_subsequence_number(&digits("12"), 3), None);
+
+        let nines = digits(&"9".repeat(50));
+        let largest = max_subsequence_number(&nines, MAX_SUBSEQUENCE_DIGITS).unwrap();
+        assert_eq!(largest.value, 10_u128.pow(38) - 1);
+        assert_eq!(
+            max_subsequence_number(&nines, MAX_SUBSEQUENCE_DIGITS + 1),
+            None
+        );
+    }
+}
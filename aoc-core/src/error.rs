@@ -0,0 +1,132 @@
+use crate::{client::SESSION_ENV, diagnostic::Diagnostic, input::INPUT_DIR_ENV, solver::Part};
+use derive_more::{Display, Error, From};
+use std::{fmt::Display, num::ParseIntError, path::PathBuf};
+use tracing::warn;
+
+pub type Result<T = (), E = AocError> = core::result::Result<T, E>;
+
+#[derive(Debug, Display, Error, From)]
+pub enum AocError {
+    /// Malformed puzzle input, `line` and `column` are 1-based, `len` is the width of the
+    /// offending token. See [`AocError::with_source`] to render it against the input
+    #[display("Parse error at {line}:{column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        len: usize,
+        message: String,
+    },
+    #[display("{_0}")]
+    #[from]
+    Diagnostic(#[error(not(source))] Box<Diagnostic>),
+    #[display(
+        "Missing input file: {} (use --input, --input-dir or {INPUT_DIR_ENV})",
+        path.display()
+    )]
+    MissingInput { path: PathBuf },
+    #[display("Wrong answer for {year} day {day} part {part}: expected {expected}, got {actual}")]
+    WrongAnswer {
+        year: u16,
+        day: u8,
+        part: Part,
+        expected: String,
+        actual: String,
+    },
+    #[display("No session token, set {SESSION_ENV} or `session` in the config file")]
+    MissingSession,
+    /// The puzzle server answered with an unexpected status, `400` usually means the session
+    /// token was rejected and `404` that the puzzle is not unlocked yet
+    #[display("{url} answered with status {status}")]
+    HttpStatus { url: String, status: u16 },
+    #[display("{_0}")]
+    #[from]
+    Http(ureq::Error),
+    /// Answer not submitted because the submission history already rules it out
+    #[display("Not submitting {answer}: {reason}")]
+    Refused { answer: String, reason: String },
+    /// Malformed `target=level` directive, from the tracing config or `RUST_LOG`
+    #[display("Invalid log filter `{directive}`: {source}")]
+    LogFilter {
+        directive: String,
+        source: tracing_subscriber::filter::ParseError,
+    },
+    #[display("{_0}")]
+    #[from]
+    ParseInt(ParseIntError),
+    #[display("{_0}")]
+    #[from]
+    Io(std::io::Error),
+    #[display("{_0}")]
+    #[from]
+    TomlDe(toml::de::Error),
+    #[display("{_0}")]
+    #[from]
+    TomlSer(toml::ser::Error),
+    #[display("{_0}")]
+    #[from]
+    Json(serde_json::Error),
+    #[display("{_0}")]
+    #[from]
+    Png(png::EncodingError),
+    #[display("{_0}")]
+    #[from]
+    Gif(gif::EncodingError),
+    #[display("{_0}")]
+    Other(#[error(not(source))] String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Display) -> Self {
+        Self::parse_span(line, column, 1, message)
+    }
+
+    pub fn parse_span(line: usize, column: usize, len: usize, message: impl Display) -> Self {
+        Self::Parse {
+            line,
+            column,
+            len,
+            message: message.to_string(),
+        }
+    }
+
+    /// Turns a [`AocError::Parse`] into a [`Diagnostic`] pointing into `source`,
+    /// other errors are returned as is
+    pub fn with_source(self, source_name: impl Display, source: &str) -> Self {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                len,
+                message,
+            } => Diagnostic::new(source_name, source, line, column, len, message).into(),
+            error => error,
+        }
+    }
+}
+
+impl From<Diagnostic> for AocError {
+    fn from(diagnostic: Diagnostic) -> Self { Self::Diagnostic(Box::new(diagnostic)) }
+}
+
+impl From<String> for AocError {
+    fn from(message: String) -> Self { Self::Other(message) }
+}
+
+impl From<&str> for AocError {
+    fn from(message: &str) -> Self { Self::Other(message.to_owned()) }
+}
+
+/// Attaches an input location to any error, turning it into [`AocError::Parse`]
+pub trait ParseContext<T>: Sized {
+    fn at_span(self, line: usize, column: usize, len: usize) -> Result<T>;
+
+    fn at(self, line: usize, column: usize) -> Result<T> { self.at_span(line, column, 1) }
+}
+
+impl<T, E: Display> ParseContext<T> for core::result::Result<T, E> {
+    fn at_span(self, line: usize, column: usize, len: usize) -> Result<T> {
+        self.map_err(|error| AocError::parse_span(line, column, len, error))
+    }
+}
+
+pub fn warn_handler(error: &AocError) { warn!("{error}") }
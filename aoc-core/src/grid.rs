@@ -0,0 +1,345 @@
+use crate::prelude::*;
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// `(x, y)` position, `x` is the column and `y` the row, `(0, 0)` is the top left
+pub type Pos = (usize, usize);
+
+/// `(dx, dy)` offsets of the 4 orthogonal neighbors: up, left, right, down
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// `(dx, dy)` offsets of the 8 neighbors, row by row from the top left
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Dense, row-major 2D grid
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone, {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// # Panics
+    /// If `cells` does not hold exactly `width * height` cells
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid should be rectangular");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line and one cell per char, every line must be as wide as the first.
+    /// Error columns are byte offsets, like the other parsers
+    pub fn parse(text: &str, mut from_char: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(text.len());
+
+        for (y, line) in text.lines().enumerate() {
+            let mut line_width = 0;
+
+            for (offset, c) in line.char_indices() {
+                cells.push(from_char(c).at_span(y + 1, offset + 1, c.len_utf8())?);
+                line_width += 1;
+            }
+
+            let width = *width.get_or_insert(line_width);
+
+            if line_width != width {
+                // First extra cell, or the end of a short line
+                let offset = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(offset, _)| offset);
+
+                return Err(AocError::parse(
+                    y + 1,
+                    offset + 1,
+                    format!("Grid row should be {width} cells wide, found {line_width}"),
+                ));
+            }
+
+            height += 1;
+        }
+
+        return Ok(Self::from_vec(width.unwrap_or(0), height, cells));
+    }
+
+    pub const fn width(&self) -> usize { self.width }
+
+    pub const fn height(&self) -> usize { self.height }
+
+    pub const fn len(&self) -> usize { self.cells.len() }
+
+    pub const fn is_empty(&self) -> bool { self.cells.is_empty() }
+
+    pub const fn in_bounds(&self, (x, y): Pos) -> bool { x < self.width && y < self.height }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.in_bounds(pos).then_some(pos.1 * self.width + pos.0)
+    }
+
+    pub fn pos_of(&self, index: usize) -> Pos { (index % self.width, index / self.width) }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> { self.cells.get(self.index_of(pos)?) }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let index = self.index_of(pos)?;
+        self.cells.get_mut(index)
+    }
+
+    pub fn cells(&self) -> &[T] { &self.cells }
+
+    pub fn cells_mut(&mut self) -> &mut [T] { &mut self.cells }
+
+    /// Moves `pos` by `(dx, dy)`, `None` when it leaves the grid
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbors(
+        &self,
+        pos: Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    /// In-bounds orthogonal neighbors, see [`NEIGHBORS_4`]
+    pub fn neighbors_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &NEIGHBORS_4)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors, see [`NEIGHBORS_8`]
+    pub fn neighbors_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &NEIGHBORS_8)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a zero width
+        self.cells.chunks_exact(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let len = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(len)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Walks from `start` in the `(dx, dy)` direction until leaving the grid, `start` included
+    pub fn ray(&self, start: Pos, direction: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.in_bounds(start).then_some(start), move |pos| {
+            self.offset(*pos, direction)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Every top-left to bottom-right diagonal, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Pos, &T)>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+
+        starts.map(|start| self.ray(start, (1, 1)))
+    }
+
+    /// Every top-right to bottom-left diagonal, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Pos, &T)>> {
+        let last_x = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(move |y| (last_x, y)));
+
+        starts.map(|start| self.ray(start, (-1, 1)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is out of the grid bounds"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is out of the grid bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", |c| c.to_digit(10).ok_or("Not a digit".into())).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let from_char = |c: char| c.to_digit(10).ok_or("Not a digit".into());
+
+        assert!(matches!(
+            Grid::parse("123\n4x6", from_char),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::parse("123\n45", from_char),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::parse("123\né4x", from_char),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                len: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::parse("12\né45", Ok),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits();
+
+        assert_eq!(
+            grid.neighbors_4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors_8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbors_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits();
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn diagonals() {
+        let grid = digits();
+        let collect = |diagonal: &mut dyn Iterator<Item = (Pos, &u32)>| {
+            diagonal.map(|(_, cell)| *cell).collect::<Vec<_>>()
+        };
+
+        let diagonals = grid
+            .diagonals()
+            .map(|mut diagonal| collect(&mut diagonal))
+            .collect::<Vec<_>>();
+        assert_eq!(diagonals, [vec![4], vec![1, 5], vec![2, 6], vec![3]]);
+
+        let anti_diagonals = grid
+            .anti_diagonals()
+            .map(|mut diagonal| collect(&mut diagonal))
+            .collect::<Vec<_>>();
+        assert_eq!(anti_diagonals, [vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    }
+}
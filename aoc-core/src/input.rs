@@ -0,0 +1,205 @@
+use crate::prelude::*;
+use derive_more::Display;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding [`default_input_dir`]
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+/// Root of the workspace, holding one `aoc<year>` crate per year
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Embeds `inputs/<file>` at compile time when the `embed-inputs` feature is enabled,
+/// otherwise expands to `None` and the input is loaded at runtime
+#[cfg(feature = "embed-inputs")]
+#[macro_export]
+macro_rules! embedded_input {
+    ($file:literal) => {
+        Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/inputs/",
+            $file
+        )))
+    };
+}
+
+/// Embeds `inputs/<file>` at compile time when the `embed-inputs` feature is enabled,
+/// otherwise expands to `None` and the input is loaded at runtime
+#[cfg(not(feature = "embed-inputs"))]
+#[macro_export]
+macro_rules! embedded_input {
+    ($file:literal) => {
+        None
+    };
+}
+
+/// Crate of `year` in the workspace
+pub fn year_dir(year: u16) -> PathBuf { Path::new(WORKSPACE_DIR).join(format!("aoc{year}")) }
+
+pub fn default_input_dir(year: u16) -> PathBuf { year_dir(year).join("inputs") }
+
+pub fn input_file_name(year: u16, day: u8) -> String { format!("{year}_{day:02}_input.txt") }
+
+pub fn example_file_name(year: u16, day: u8) -> String { format!("{year}_{day:02}_input_test.txt") }
+
+/// Finds puzzle inputs, in order of priority:
+/// an explicit directory (CLI), the embedded input, [`INPUT_DIR_ENV`], then [`default_input_dir`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct InputLoader {
+    pub dir: Option<PathBuf>,
+}
+
+impl InputLoader {
+    pub fn new(dir: Option<PathBuf>) -> Self { Self { dir } }
+
+    pub fn resolved_dir(&self, year: u16) -> PathBuf {
+        if let Some(dir) = &self.dir {
+            return dir.clone();
+        }
+
+        return std::env::var_os(INPUT_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| default_input_dir(year));
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.resolved_dir(year).join(input_file_name(year, day))
+    }
+
+    pub fn example_path(&self, year: u16, day: u8) -> PathBuf {
+        self.resolved_dir(year).join(example_file_name(year, day))
+    }
+
+    /// Example input of `day`, never embedded
+    pub fn load_example(&self, day: &Day) -> Result<String> {
+        load_file(self.example_path(day.year, day.day))
+    }
+
+    /// Where [`InputLoader::load`] reads the input of `day` from, used in diagnostics
+    pub fn source_name(&self, day: &Day) -> String {
+        let path = self.path(day.year, day.day);
+
+        if self.dir.is_none() && day.embedded_input.is_some() {
+            return format!("<embedded {}>", input_file_name(day.year, day.day));
+        }
+
+        return path.display().to_string();
+    }
+
+    pub fn load(&self, day: &Day) -> Result<Cow<'static, str>> {
+        if self.dir.is_none()
+            && let Some(embedded) = day.embedded_input
+        {
+            debug!("Using embedded input for day {}", day.day);
+            return Ok(Cow::Borrowed(embedded));
+        }
+
+        return load_file(self.path(day.year, day.day)).map(Cow::Owned);
+    }
+}
+
+pub fn load_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+
+    if !path.is_file() {
+        return Err(AocError::MissingInput {
+            path: path.to_owned(),
+        });
+    }
+
+    debug!("Loading input from {}", path.display());
+
+    return Ok(std::fs::read_to_string(path)?);
+}
+
+/// Lines shown at each end of the input by [`log_input`]
+pub const PREVIEW_LINES: usize = 3;
+
+/// Size and shape of a puzzle input, line lengths are in bytes
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default, Display)]
+#[display(
+    "{bytes} bytes, {lines} lines, line length {min_line_len}..={max_line_len} \
+     (mean {mean_line_len})"
+)]
+pub struct InputStats {
+    pub bytes: usize,
+    pub lines: usize,
+    pub min_line_len: usize,
+    pub max_line_len: usize,
+    pub mean_line_len: usize,
+}
+
+impl InputStats {
+    pub fn new(text_input: &str) -> Self {
+        let (lines, total, min, max) = text_input
+            .lines()
+            .map(str::len)
+            .fold((0, 0, usize::MAX, 0), |(lines, total, min, max), len| {
+                (lines + 1, total + len, min.min(len), max.max(len))
+            });
+
+        return Self {
+            bytes: text_input.len(),
+            lines,
+            min_line_len: min.min(max),
+            max_line_len: max,
+            mean_line_len: total.checked_div(lines).unwrap_or_default(),
+        };
+    }
+}
+
+/// First and last `lines` lines of `text_input`, with the number of lines skipped in between
+pub fn input_preview(text_input: &str, lines: usize) -> String {
+    let all = text_input.lines().collect::<Vec<_>>();
+
+    if all.len() <= 2 * lines {
+        return all.join("\n");
+    }
+
+    return format!(
+        "{}\n... {} more lines ...\n{}",
+        all[..lines].join("\n"),
+        all.len() - 2 * lines,
+        all[all.len() - lines..].join("\n")
+    );
+}
+
+/// Logs the [`InputStats`] and an [`input_preview`] at `debug` level, the whole input is only
+/// logged at `trace` level
+pub fn log_input(text_input: &str) {
+    debug!("Input: {}", InputStats::new(text_input));
+    debug!(
+        "\n---Input preview---\n{}\n---EOF---",
+        input_preview(text_input, PREVIEW_LINES)
+    );
+    trace!("\n---Input---\n{text_input}\n---EOF---");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview() {
+        let text_input = "a\nbbb\ncc\ndddd\ne\nff\ng\n";
+
+        assert_eq!(
+            InputStats::new(text_input),
+            InputStats {
+                bytes: 21,
+                lines: 7,
+                min_line_len: 1,
+                max_line_len: 4,
+                mean_line_len: 2,
+            }
+        );
+        assert_eq!(InputStats::new(""), InputStats::default());
+
+        assert_eq!(
+            input_preview(text_input, 2),
+            "a\nbbb\n... 3 more lines ...\nff\ng"
+        );
+        assert_eq!(input_preview(text_input, 4), text_input.trim_end());
+    }
+}
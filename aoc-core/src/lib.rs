@@ -0,0 +1,24 @@
+// Lets `#[derive(AocParse)]` refer to `::aoc_core` from inside this crate too
+extern crate self as aoc_core;
+
+pub mod answers;
+pub mod automaton;
+pub mod client;
+pub mod diagnostic;
+pub mod digits;
+pub mod error;
+pub mod grid;
+pub mod input;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+pub mod modular;
+pub mod parsing;
+pub mod prelude;
+pub mod range_set;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod timing;
+pub mod tracing;
+pub mod visualize;
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+use std::{
+    collections::HashSet,
+    io::Cursor,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+use tiny_http::{Method, Request, Response, Server};
+
+pub const MOCK_FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+/// Session token accepted by the mock server unless told otherwise
+pub const MOCK_SESSION: &str = "mock-session";
+
+/// What a [`MockServer`] serves
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct MockPuzzles {
+    /// `<year>_<day>_puzzle.html` and `<year>_<day>_input.txt` files, and an `answers.toml`
+    /// in the [`AnswerStore`] format
+    pub fixtures: PathBuf,
+    pub session: String,
+    /// Delay after a wrong answer before the next one is judged
+    pub cooldown: Duration,
+}
+
+impl Default for MockPuzzles {
+    fn default() -> Self {
+        Self {
+            fixtures: PathBuf::from(MOCK_FIXTURES_DIR),
+            session: MOCK_SESSION.to_owned(),
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Stand-in for the puzzle website, so that the [`Client`] can be tested without network:
+/// - `GET /<year>/day/<day>` serves `<year>_<day>_puzzle.html`
+/// - `GET /<year>/day/<day>/input` serves `<year>_<day>_input.txt`
+/// - `POST /<year>/day/<day>/answer` judges the `level` and `answer` form fields against
+///   `answers.toml` with the website's wording, including the cooldown after a wrong answer
+///
+/// Like the website, the input and answer pages need the `session=<session>` cookie
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    requests: Arc<AtomicUsize>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Serves from a background thread until dropped, port 0 picks a free port
+    pub fn start(addr: &str, puzzles: MockPuzzles) -> Result<Self> {
+        let answers = AnswerStore::load(puzzles.fixtures.join("answers.toml"))?;
+        let server =
+            Server::http(addr).map_err(|error| format!("Can't listen on {addr}: {error}"))?;
+        let Some(local_addr) = server.server_addr().to_ip() else {
+            return Err(format!("{addr} is not a TCP address").into());
+        };
+
+        let server = Arc::new(server);
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let thread = std::thread::spawn({
+            let (server, requests) = (server.clone(), requests.clone());
+            let mut state = MockState {
+                puzzles,
+                answers,
+                solved: HashSet::new(),
+                judged_at: None,
+            };
+
+            move || {
+                for mut request in server.incoming_requests() {
+                    requests.fetch_add(1, Ordering::Relaxed);
+
+                    let response = state.respond(&mut request);
+                    debug!(
+                        "{} {} -> {}",
+                        request.method(),
+                        request.url(),
+                        response.status_code().0
+                    );
+
+                    if let Err(error) = request.respond(response) {
+                        warn!("Mock server could not answer: {error}");
+                    }
+                }
+            }
+        });
+
+        return Ok(Self {
+            server,
+            url: format!("http://{local_addr}"),
+            requests,
+            thread: Some(thread),
+        });
+    }
+
+    pub fn url(&self) -> &str { &self.url }
+
+    /// Requests received so far
+    pub fn requests(&self) -> usize { self.requests.load(Ordering::Relaxed) }
+
+    /// Serves until the process is stopped
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+type MockResponse = Response<Cursor<Vec<u8>>>;
+
+struct MockState {
+    puzzles: MockPuzzles,
+    answers: AnswerStore,
+    solved: HashSet<(u16, u8, Part)>,
+    /// Last wrong answer, answers are not judged during the cooldown that follows
+    judged_at: Option<Instant>,
+}
+
+impl MockState {
+    fn respond(&mut self, request: &mut Request) -> MockResponse {
+        let path = request.url().split('?').next().unwrap_or_default();
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        let Some((year, day, page)) = (match segments[..] {
+            [year, "day", day, ref page @ ..] => year
+                .parse::<u16>()
+                .ok()
+                .zip(day.parse::<u8>().ok())
+                .map(|(year, day)| (year, day, page.first().copied())),
+            _ => None,
+        }) else {
+            return not_found();
+        };
+
+        if page.is_some() && !has_session(request, &self.puzzles.session) {
+            return Response::from_string(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            )
+            .with_status_code(400);
+        }
+
+        let file = match (request.method(), page) {
+            (Method::Get, None) => format!("{year}_{day:02}_puzzle.html"),
+            (Method::Get, Some("input")) => input_file_name(year, day),
+            (Method::Post, Some("answer")) => {
+                let mut form = String::new();
+                if request.as_reader().read_to_string(&mut form).is_err() {
+                    return Response::from_string("400 Bad Request\n").with_status_code(400);
+                }
+
+                return self.judge(year, day, &form);
+            }
+            _ => return not_found(),
+        };
+
+        return match std::fs::read(self.puzzles.fixtures.join(file)) {
+            Ok(body) => Response::from_data(body),
+            Err(_) => not_found(),
+        };
+    }
+
+    fn judge(&mut self, year: u16, day: u8, form: &str) -> MockResponse {
+        let field = |name: &str| {
+            form.split('&')
+                .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                .map(|value| value.replace('+', " "))
+        };
+        let part = field("level").and_then(|level| level.parse::<Part>().ok());
+        let (Some(part), Some(answer)) = (part, field("answer")) else {
+            return Response::from_string("400 Bad Request\n").with_status_code(400);
+        };
+
+        let back = format!("[<a href=\"/{year}/day/{day}\">Return to Day {day}</a>]");
+        let Some(expected) = self
+            .answers
+            .get(year, day)
+            .and_then(|report| report.get(part))
+        else {
+            return not_found();
+        };
+
+        if self.solved.contains(&(year, day, part)) {
+            return answer_page(format!(
+                "You don't seem to be solving the right level.  Did you already complete it? {back}"
+            ));
+        }
+
+        if let Some(judged_at) = self.judged_at {
+            let left = self.puzzles.cooldown.saturating_sub(judged_at.elapsed());
+
+            if !left.is_zero() {
+                return answer_page(format!(
+                    "You gave an answer too recently; you have to wait after submitting an \
+                     answer before trying again.  You have {} left to wait. {back}",
+                    wait_text(left)
+                ));
+            }
+        }
+
+        if answer == expected {
+            self.solved.insert((year, day, part));
+            return answer_page(format!(
+                "That's the right answer!  You are one gold star closer to finishing. {back}"
+            ));
+        }
+
+        self.judged_at = Some(Instant::now());
+
+        let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+        let wait = match self.puzzles.cooldown.as_secs() / 60 {
+            0 => String::new(),
+            1 => "  Please wait one minute before trying again.".to_owned(),
+            minutes => format!("  Please wait {minutes} minutes before trying again."),
+        };
+
+        return answer_page(format!("That's not the right answer{hint}.{wait} {back}"));
+    }
+}
+
+fn answer_page(text: String) -> MockResponse {
+    Response::from_string(format!(
+        "<html><body><main><article><p>{text}</p></article></main></body></html>\n"
+    ))
+}
+
+/// `1m 5s` or `42s`, rounded up to the next second like the real site
+fn wait_text(left: Duration) -> String {
+    let seconds = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+
+    return match seconds / 60 {
+        0 => format!("{seconds}s"),
+        minutes => format!("{minutes}m {}s", seconds % 60),
+    };
+}
+
+fn not_found() -> MockResponse { Response::from_string("404 Not Found\n").with_status_code(404) }
+
+fn has_session(request: &Request, session: &str) -> bool {
+    let expected = format!("session={session}");
+
+    return request
+        .headers()
+        .iter()
+        .filter(|header| header.field.equiv("Cookie"))
+        .flat_map(|header| header.value.as_str().split(';'))
+        .any(|cookie| cookie.trim() == expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_rounding() {
+        assert_eq!(wait_text(Duration::from_millis(59_500)), "1m 0s");
+        assert_eq!(wait_text(Duration::from_secs(60)), "1m 0s");
+        assert_eq!(wait_text(Duration::from_millis(60_001)), "1m 1s");
+        assert_eq!(wait_text(Duration::from_secs(42)), "42s");
+    }
+}
@@ -0,0 +1,206 @@
+use derive_more::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Integer modulo `M`, always kept in `0..M`. `M` can't be 0, it fails to compile:
+///
+/// ```compile_fail
+/// aoc_core::modular::Modular::<0>::new(1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default, Display)]
+#[display("{_0}")]
+pub struct Modular<const M: u64>(u64);
+
+/// Outcome of [`Modular::rotate`]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Turn<const M: u64> {
+    /// Position after the rotation
+    pub value: Modular<M>,
+    /// Number of whole turns, `|delta| / M`
+    pub revolutions: u64,
+    /// Number of steps landing on 0, the starting position excluded and the final one included
+    pub zero_crossings: u64,
+}
+
+impl<const M: u64> Modular<M> {
+    /// Evaluated by every operation that divides by `M`, so that `Modular<0>` is rejected when
+    /// it is used instead of panicking at runtime
+    const NON_ZERO_MODULUS: () = assert!(M > 0, "Modular<M> needs M > 0");
+
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self::new(1);
+
+    pub const fn new(value: u64) -> Self {
+        let () = Self::NON_ZERO_MODULUS;
+        return Self(value % M);
+    }
+
+    pub const fn from_signed(value: i64) -> Self {
+        let () = Self::NON_ZERO_MODULUS;
+        return Self((value as i128).rem_euclid(M as i128) as _);
+    }
+
+    pub const fn value(self) -> u64 { self.0 }
+
+    /// Moves `delta` steps, forward when positive, one step at a time like a dial
+    pub const fn rotate(self, delta: i64) -> Turn<M> {
+        let () = Self::NON_ZERO_MODULUS;
+        let distance = delta.unsigned_abs();
+
+        let zero_crossings = if delta >= 0 {
+            ((self.0 as u128 + distance as u128) / M as u128) as _
+        } else if self.0 == 0 {
+            distance / M
+        } else if distance >= self.0 {
+            (distance - self.0) / M + 1
+        } else {
+            0
+        };
+
+        return Turn {
+            value: Self((self.0 as i128 + delta as i128).rem_euclid(M as i128) as _),
+            revolutions: distance / M,
+            zero_crossings,
+        };
+    }
+
+    /// Exponentiation by squaring
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::ONE;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+
+            base *= base;
+            exp >>= 1;
+        }
+
+        return result;
+    }
+
+    /// Multiplicative inverse, `None` when `self` and `M` are not coprime
+    pub fn inverse(self) -> Option<Self> {
+        // Extended Euclid, tracking only the coefficient of `self`
+        let () = Self::NON_ZERO_MODULUS;
+        let (mut r0, mut r1) = (M as i128, self.0 as i128);
+        let (mut t0, mut t1) = (0_i128, 1_i128);
+
+        while r1 != 0 {
+            let quotient = r0 / r1;
+            (r0, r1) = (r1, r0 - quotient * r1);
+            (t0, t1) = (t1, t0 - quotient * t1);
+        }
+
+        if r0 != 1 {
+            return None;
+        }
+
+        return Some(Self(t0.rem_euclid(M as i128) as _));
+    }
+}
+
+impl<const M: u64> From<u64> for Modular<M> {
+    fn from(value: u64) -> Self { Self::new(value) }
+}
+
+impl<const M: u64> From<i64> for Modular<M> {
+    fn from(value: i64) -> Self { Self::from_signed(value) }
+}
+
+impl<const M: u64> Add for Modular<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let () = Self::NON_ZERO_MODULUS;
+        Self(((self.0 as u128 + rhs.0 as u128) % M as u128) as _)
+    }
+}
+
+impl<const M: u64> Sub for Modular<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+}
+
+impl<const M: u64> Mul for Modular<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let () = Self::NON_ZERO_MODULUS;
+        Self(((self.0 as u128 * rhs.0 as u128) % M as u128) as _)
+    }
+}
+
+impl<const M: u64> Neg for Modular<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let () = Self::NON_ZERO_MODULUS;
+        Self((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> AddAssign for Modular<M> {
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl<const M: u64> SubAssign for Modular<M> {
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl<const M: u64> MulAssign for Modular<M> {
+    fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Dial = Modular<100>;
+
+    #[test]
+    fn arithmetic() {
+        let a = Modular::<7>::new(5);
+        let b = Modular::<7>::from_signed(-4);
+
+        assert_eq!(b.value(), 3);
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((b - a).value(), 5);
+        assert_eq!((a * b).value(), 1);
+        assert_eq!((-Modular::<7>::ZERO).value(), 0);
+        assert_eq!(
+            (Modular::<{ u64::MAX }>::new(u64::MAX - 1) * Modular::new(2)).value(),
+            u64::MAX - 2
+        );
+    }
+
+    #[test]
+    fn pow_and_inverse() {
+        assert_eq!(Modular::<1_000_000_007>::new(2).pow(30).value(), 73_741_817);
+        assert_eq!(Modular::<13>::new(5).pow(0), Modular::ONE);
+
+        assert_eq!(Modular::<7>::new(3).inverse(), Some(Modular::new(5)));
+        assert_eq!(Dial::new(7).inverse(), Some(Dial::new(43)));
+        assert_eq!(Dial::new(10).inverse(), None);
+    }
+
+    #[test]
+    fn rotate() {
+        let Turn {
+            value,
+            revolutions,
+            zero_crossings,
+        } = Dial::new(50).rotate(1000);
+        assert_eq!((value.value(), revolutions, zero_crossings), (50, 10, 10));
+
+        assert_eq!(Dial::new(50).rotate(-68).zero_crossings, 1);
+        assert_eq!(Dial::new(0).rotate(-5).zero_crossings, 0);
+        assert_eq!(Dial::new(5).rotate(-5).zero_crossings, 1);
+        assert_eq!(Dial::new(5).rotate(-205).zero_crossings, 3);
+        assert_eq!(Dial::new(95).rotate(5).zero_crossings, 1);
+        assert_eq!(Dial::new(95).rotate(4).zero_crossings, 0);
+        assert_eq!(Dial::new(0).rotate(-300).value, Dial::ZERO);
+    }
+}
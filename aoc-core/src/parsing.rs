@@ -0,0 +1,664 @@
+//! Zero-allocation parsing straight from the input bytes
+
+use crate::prelude::*;
+use itertools::Either;
+use std::{iter::FusedIterator, ops::RangeInclusive};
+
+pub use aoc_derive::AocParse;
+
+/// Integers that can be scanned from ASCII digits
+pub trait Integer: Copy {
+    const SIGNED: bool;
+
+    /// Parses the leading integer of `bytes`, returning it with the number of bytes read.
+    /// `None` when `bytes` does not start with an integer or it overflows
+    fn scan(bytes: &[u8]) -> Option<(Self, usize)>;
+}
+
+macro_rules! impl_integer {
+    (unsigned: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = false;
+
+                fn scan(bytes: &[u8]) -> Option<(Self, usize)> {
+                    let digits = bytes.strip_prefix(b"+").unwrap_or(bytes);
+
+                    let (value, len) = scan_digits(digits, |acc: Self, digit| {
+                        acc.checked_mul(10)?.checked_add(digit as Self)
+                    })?;
+
+                    return Some((value, bytes.len() - digits.len() + len));
+                }
+            }
+        )*
+    };
+    (signed: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = true;
+
+                fn scan(bytes: &[u8]) -> Option<(Self, usize)> {
+                    let (negative, digits) = match bytes.first() {
+                        Some(b'-') => (true, &bytes[1..]),
+                        Some(b'+') => (false, &bytes[1..]),
+                        _ => (false, bytes),
+                    };
+                    let sign_len = bytes.len() - digits.len();
+
+                    // Accumulating towards the sign keeps `MIN` representable
+                    let (value, len) = scan_digits(digits, |acc: Self, digit| {
+                        let acc = acc.checked_mul(10)?;
+
+                        return match negative {
+                            true => acc.checked_sub(digit as Self),
+                            false => acc.checked_add(digit as Self),
+                        };
+                    })?;
+
+                    return Some((value, sign_len + len));
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+
+fn scan_digits<T: Default>(
+    bytes: &[u8],
+    mut push: impl FnMut(T, u8) -> Option<T>,
+) -> Option<(T, usize)> {
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+
+    if len == 0 {
+        return None;
+    }
+
+    let value = bytes[..len]
+        .iter()
+        .try_fold(T::default(), |acc, byte| push(acc, byte - b'0'))?;
+
+    return Some((value, len));
+}
+
+/// Parses `bytes` as a whole integer, like [`str::parse`] but without going through UTF-8.
+/// Errors are an [`AocError::Parse`] relative to `bytes`, pointing at the invalid digit if any
+pub fn parse_int<T: Integer>(bytes: &[u8]) -> Result<T> {
+    match T::scan(bytes) {
+        Some((value, len)) if len == bytes.len() => Ok(value),
+        Some((_, len)) => Err(AocError::parse(
+            1,
+            len + 1,
+            format!("Invalid digit `{}` found", bytes[len].escape_ascii()),
+        )),
+        None if bytes.is_empty() => Err(AocError::parse_span(
+            1,
+            1,
+            0,
+            "Cannot parse an integer from an empty string",
+        )),
+        None => {
+            let sign_len = match bytes[0] {
+                b'+' => 1,
+                b'-' if T::SIGNED => 1,
+                _ => 0,
+            };
+            let message = match bytes.get(sign_len).is_some_and(u8::is_ascii_digit) {
+                true => "Integer is too large".to_owned(),
+                false => format!("Invalid integer `{}`", bytes.escape_ascii()),
+            };
+
+            Err(AocError::parse_span(1, 1, bytes.len(), message))
+        }
+    }
+}
+
+/// Every integer of `bytes`, skipping whatever separates them. A `-` directly before the
+/// digits is read as a sign for signed types only
+pub fn ints<T: Integer>(bytes: &[u8]) -> Ints<'_, T> {
+    Ints {
+        bytes,
+        _integer: std::marker::PhantomData,
+    }
+}
+
+/// See [`ints`]
+#[derive(Clone, Debug)]
+pub struct Ints<'b, T> {
+    bytes: &'b [u8],
+    _integer: std::marker::PhantomData<T>,
+}
+
+impl<T: Integer> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.bytes.iter().position(u8::is_ascii_digit)?;
+            let start = match T::SIGNED && start > 0 && self.bytes[start - 1] == b'-' {
+                true => start - 1,
+                false => start,
+            };
+
+            let rest = &self.bytes[start..];
+
+            match T::scan(rest) {
+                Some((value, len)) => {
+                    self.bytes = &rest[len..];
+                    return Some(value);
+                }
+                // Overflowing integers are skipped
+                None => {
+                    let len = rest
+                        .iter()
+                        .skip(1)
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    self.bytes = &rest[1 + len..];
+                }
+            }
+        }
+    }
+}
+
+impl<T: Integer> FusedIterator for Ints<'_, T> {}
+
+/// Value of an ASCII digit
+pub const fn digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        _ => None,
+    }
+}
+
+/// Digit values of exactly `N` ASCII digits, `None` on a wrong length or non-digit
+pub fn digits<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+    let bytes: &[u8; N] = bytes.try_into().ok()?;
+    let mut digits = [0; N];
+
+    for (digit_value, byte) in digits.iter_mut().zip(bytes) {
+        *digit_value = digit(*byte)?;
+    }
+
+    return Some(digits);
+}
+
+/// Splits `bytes` around the first occurrence of `delimiter`
+pub fn split_once<'b>(bytes: &'b [u8], delimiter: &[u8]) -> Option<(&'b [u8], &'b [u8])> {
+    if delimiter.is_empty() {
+        return None;
+    }
+
+    let start = bytes
+        .windows(delimiter.len())
+        .position(|window| window == delimiter)?;
+
+    return Some((&bytes[..start], &bytes[start + delimiter.len()..]));
+}
+
+/// Fields of `bytes` between every `delimiter`, with their byte offset into `bytes`
+pub fn split(bytes: &[u8], delimiter: u8) -> Split<'_> {
+    Split {
+        bytes,
+        offset: 0,
+        delimiter,
+        finished: false,
+    }
+}
+
+/// Lines of `bytes` with their byte offset, like [`str::lines`]: a final line ending does
+/// not produce an empty line and a trailing `\r` is stripped
+pub fn lines(bytes: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let empty = bytes.is_empty();
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+
+    split(bytes, b'\n')
+        .filter(move |_| !empty)
+        .map(|(offset, line)| (offset, line.strip_suffix(b"\r").unwrap_or(line)))
+}
+
+/// See [`split`]
+#[derive(Clone, Debug)]
+pub struct Split<'b> {
+    bytes: &'b [u8],
+    offset: usize,
+    delimiter: u8,
+    finished: bool,
+}
+
+impl<'b> Iterator for Split<'b> {
+    type Item = (usize, &'b [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let offset = self.offset;
+
+        let field = match self.bytes.iter().position(|byte| *byte == self.delimiter) {
+            Some(end) => {
+                let (field, rest) = self.bytes.split_at(end);
+                self.bytes = &rest[1..];
+                self.offset += end + 1;
+                field
+            }
+            None => {
+                self.finished = true;
+                self.bytes
+            }
+        };
+
+        return Some((offset, field));
+    }
+}
+
+impl FusedIterator for Split<'_> {}
+
+/// Parser declared on the type, usually with `#[derive(AocParse)]`:
+///
+/// - `#[aoc(sep = "-")]` on a struct splits the input into one field per separator,
+///   the last field takes the rest
+/// - `#[aoc(blocks)]` on a struct is `sep = "\n\n"`, one field per block of lines
+/// - `#[aoc(sep = ",")]` on a field parses it with [`ParseSeparated`]
+/// - `#[aoc(prefix = "L")]` on every variant of an enum picks the first variant whose prefix
+///   starts the input, the rest is parsed like a struct
+///
+/// Every type parameter of a derived type gets an `AocParse` bound
+///
+/// Error locations are relative to the start of `bytes`, see [`relocate`]
+pub trait AocParse: Sized {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self>;
+}
+
+/// Collections parsed from the items between a separator, see [`separated`]
+pub trait ParseSeparated: Sized {
+    fn parse_separated(bytes: &[u8], separator: &[u8]) -> Result<Self>;
+}
+
+/// Byte offset of a field into its parent, with the field itself
+pub type Field<'b> = (usize, &'b [u8]);
+
+macro_rules! impl_aoc_parse_integer {
+    ($($t:ty),*) => {
+        $(
+            impl AocParse for $t {
+                fn aoc_parse(bytes: &[u8]) -> Result<Self> { parse_int(bytes) }
+            }
+        )*
+    };
+}
+
+impl_aoc_parse_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl AocParse for String {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self> {
+        String::from_utf8(bytes.to_vec()).at_span(1, 1, bytes.len())
+    }
+}
+
+/// `start-end`, the bounds can't be negative
+impl<T: AocParse> AocParse for RangeInclusive<T> {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self> {
+        let [start, end] = split_fields(bytes, b"-")?;
+
+        return Ok(RangeInclusive::new(
+            parse_at(bytes, start)?,
+            parse_at(bytes, end)?,
+        ));
+    }
+}
+
+/// One item per line
+impl<T: AocParse> AocParse for Vec<T> {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self> { Self::parse_separated(bytes, b"\n") }
+}
+
+impl<T: AocParse> ParseSeparated for Vec<T> {
+    fn parse_separated(bytes: &[u8], separator: &[u8]) -> Result<Self> {
+        separated(bytes, separator)
+            .map(|field| parse_at(bytes, field))
+            .collect()
+    }
+}
+
+/// One range per line
+impl<T: AocParse + RangeBound> AocParse for RangeSet<T> {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self> { Self::parse_separated(bytes, b"\n") }
+}
+
+impl<T: AocParse + RangeBound> ParseSeparated for RangeSet<T> {
+    fn parse_separated(bytes: &[u8], separator: &[u8]) -> Result<Self> {
+        separated(bytes, separator)
+            .map(|field| parse_at::<RangeInclusive<T>>(bytes, field))
+            .collect()
+    }
+}
+
+/// Items between every `separator`. Separators containing a line break split the whole input
+/// and keep blank items, like [`lines`] does for `\n`. Other separators split each line and
+/// skip blank items, so lists may wrap over several lines
+pub fn separated<'b>(bytes: &'b [u8], separator: &'b [u8]) -> impl Iterator<Item = Field<'b>> + 'b {
+    if separator == b"\n" {
+        return Either::Left(Either::Left(lines(bytes)));
+    }
+
+    if separator.contains(&b'\n') {
+        let empty = bytes.is_empty();
+        let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+
+        return Either::Left(Either::Right(
+            split_all(bytes, separator).filter(move |_| !empty),
+        ));
+    }
+
+    return Either::Right(lines(bytes).flat_map(move |(line_offset, line)| {
+        split_all(line, separator)
+            .filter(|(_, item)| !item.is_empty())
+            .map(move |(offset, item)| (line_offset + offset, item))
+    }));
+}
+
+fn split_all<'b>(bytes: &'b [u8], separator: &'b [u8]) -> impl Iterator<Item = Field<'b>> {
+    let mut rest = Some((0, bytes));
+
+    std::iter::from_fn(move || {
+        let (offset, field) = rest?;
+
+        match split_once(field, separator) {
+            Some((head, tail)) => {
+                rest = Some((offset + head.len() + separator.len(), tail));
+                return Some((offset, head));
+            }
+            None => return rest.take(),
+        }
+    })
+}
+
+/// Splits `bytes` into exactly `N` fields around the first `N - 1` separators,
+/// the last field takes the rest
+pub fn split_fields<'b, const N: usize>(
+    bytes: &'b [u8],
+    separator: &[u8],
+) -> Result<[Field<'b>; N]> {
+    let mut fields = [(0, &bytes[..0]); N];
+    let mut offset = 0;
+
+    for field in fields.iter_mut().take(N.saturating_sub(1)) {
+        let rest = &bytes[offset..];
+
+        let Some((head, _)) = split_once(rest, separator) else {
+            return Err(missing_separator(bytes, offset, separator));
+        };
+
+        *field = (offset, head);
+        offset += head.len() + separator.len();
+    }
+
+    if let Some(last) = fields.last_mut() {
+        *last = (offset, &bytes[offset..]);
+    }
+
+    return Ok(fields);
+}
+
+/// A missing line separator points past the last line, any other spans the unsplit rest
+fn missing_separator(bytes: &[u8], offset: usize, separator: &[u8]) -> AocError {
+    if separator.starts_with(b"\n") {
+        return AocError::parse(
+            lines(bytes).count() + 1,
+            1,
+            match separator {
+                b"\n\n" => "Input should contain an empty line".to_owned(),
+                _ => format!("Input should contain `{}`", separator.escape_ascii()),
+            },
+        );
+    }
+
+    let rest = &bytes[offset..];
+    let rest_line = rest.split(|byte| *byte == b'\n').next().unwrap_or(rest);
+
+    return relocate(
+        AocError::parse_span(
+            1,
+            1,
+            rest_line.len(),
+            format!(
+                "`{}` should contain `{}`",
+                rest_line.escape_ascii(),
+                separator.escape_ascii()
+            ),
+        ),
+        bytes,
+        offset,
+    );
+}
+
+/// Error of an enum parser when no variant prefix matches
+pub fn unknown_prefix(bytes: &[u8], prefixes: &[&[u8]]) -> AocError {
+    let line = bytes.split(|byte| *byte == b'\n').next().unwrap_or(bytes);
+    let expected = prefixes
+        .iter()
+        .map(|prefix| format!("`{}`", prefix.escape_ascii()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    return AocError::parse_span(
+        1,
+        1,
+        line.len(),
+        format!(
+            "Unknown `{}`, expected one of {expected}",
+            line.escape_ascii()
+        ),
+    );
+}
+
+/// Turns the location of a parse error found in `parent[offset..]` into a location in `parent`
+pub fn relocate(error: AocError, parent: &[u8], offset: usize) -> AocError {
+    let AocError::Parse {
+        line,
+        column,
+        len,
+        message,
+    } = error
+    else {
+        return error;
+    };
+
+    let before = &parent[..offset];
+    let lines_before = before.iter().filter(|byte| **byte == b'\n').count();
+    let line_start = before
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |i| i + 1);
+
+    return AocError::Parse {
+        line: lines_before + line,
+        column: match line {
+            1 => offset - line_start + column,
+            _ => column,
+        },
+        len,
+        message,
+    };
+}
+
+/// Parses a field of `parent`, with error locations relative to `parent`
+pub fn parse_at<T: AocParse>(parent: &[u8], (offset, field): Field) -> Result<T> {
+    T::aoc_parse(field).map_err(|error| relocate(error, parent, offset))
+}
+
+/// Parses a separated field of `parent`, with error locations relative to `parent`
+pub fn parse_separated_at<T: ParseSeparated>(
+    parent: &[u8],
+    (offset, field): Field,
+    separator: &[u8],
+) -> Result<T> {
+    T::parse_separated(field, separator).map_err(|error| relocate(error, parent, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_integers() {
+        assert_eq!(u64::scan(b"123-4"), Some((123, 3)));
+        assert_eq!(u8::scan(b"256"), None);
+        assert_eq!(u8::scan(b"-1"), None);
+        assert_eq!(i8::scan(b"-128,"), Some((-128, 4)));
+        assert_eq!(i8::scan(b"+12"), Some((12, 3)));
+        assert_eq!(i32::scan(b"-"), None);
+    }
+
+    #[test]
+    fn parse_integers() {
+        assert_eq!(parse_int::<u64>(b"18446744073709551615").unwrap(), u64::MAX);
+        assert_eq!(parse_int::<i64>(b"-68").unwrap(), -68);
+        assert!(parse_int::<u64>(b"4x").is_err());
+        assert!(parse_int::<u64>(b"").is_err());
+        assert!(parse_int::<u8>(b"300").is_err());
+    }
+
+    #[test]
+    fn integer_error_spans() {
+        assert!(matches!(
+            parse_int::<u64>(b"4x2"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 2,
+                len: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_int::<u8>(b"300"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 1,
+                len: 3,
+                ..
+            })
+        ));
+        // Relocated into the parent, on the second line
+        assert!(matches!(
+            Vec::<u32>::parse_separated(b"12\n34x5", b"\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                len: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn all_ints() {
+        let line = b"Sensor at x=-2, y=15: 3-5";
+
+        assert_eq!(ints::<i32>(line).collect::<Vec<_>>(), [-2, 15, 3, -5]);
+        assert_eq!(ints::<u32>(line).collect::<Vec<_>>(), [2, 15, 3, 5]);
+        assert_eq!(ints::<u8>(b"1 999 2").collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(ints::<u8>(b"no digits").count(), 0);
+    }
+
+    #[test]
+    fn digit_arrays() {
+        assert_eq!(digits::<3>(b"907"), Some([9, 0, 7]));
+        assert_eq!(digits::<3>(b"97"), None);
+        assert_eq!(digits::<2>(b"9a"), None);
+    }
+
+    #[test]
+    fn splitting() {
+        assert_eq!(
+            split(b"11-22,,95", b',').collect::<Vec<_>>(),
+            [(0, &b"11-22"[..]), (6, b""), (7, b"95")]
+        );
+        assert_eq!(
+            lines(b"a\r\nbc\n").collect::<Vec<_>>(),
+            [(0, &b"a"[..]), (3, b"bc")]
+        );
+        assert_eq!(lines(b"").count(), 0);
+        assert_eq!(lines(b"\n").count(), 1);
+        assert_eq!(lines(b"\n\nx").count(), 3);
+        assert_eq!(
+            split_once(b"3-5\n\n1", b"\n\n"),
+            Some((&b"3-5"[..], &b"1"[..]))
+        );
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(sep = " -> ")]
+    struct Edge(u8, u8);
+
+    #[derive(Debug, PartialEq, AocParse)]
+    enum Cell {
+        #[aoc(prefix = ".")]
+        Empty,
+        #[aoc(prefix = "#")]
+        Wall,
+        #[aoc(prefix = "move ")]
+        Move(Edge),
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(blocks)]
+    struct Puzzle {
+        #[aoc(sep = ",")]
+        seeds: Vec<u32>,
+        cells: Vec<Cell>,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(sep = "..")]
+    struct Span<T> {
+        start: T,
+        #[aoc(sep = ",")]
+        steps: Vec<T>,
+    }
+
+    #[test]
+    fn derive() -> Result {
+        let puzzle = Puzzle::aoc_parse(b"1,2,\n3\n\n.\n#\nmove 4 -> 5\n")?;
+
+        assert_eq!(
+            puzzle,
+            Puzzle {
+                seeds: vec![1, 2, 3],
+                cells: vec![Cell::Empty, Cell::Wall, Cell::Move(Edge(4, 5))],
+            }
+        );
+        assert_eq!(
+            Span::<u8>::aoc_parse(b"3..4,5")?,
+            Span {
+                start: 3,
+                steps: vec![4, 5],
+            }
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn derive_errors() {
+        let error_at = |input: &[u8]| match Puzzle::aoc_parse(input) {
+            Err(AocError::Parse { line, column, .. }) => Some((line, column)),
+            _ => None,
+        };
+
+        assert_eq!(error_at(b"1,2"), Some((2, 1)));
+        assert_eq!(error_at(b"1,x2\n\n."), Some((1, 3)));
+        assert_eq!(error_at(b"1\n\n.\n.\n+"), Some((5, 1)));
+        assert_eq!(error_at(b"1\n\nmove 4 => 5"), Some((3, 6)));
+        assert_eq!(error_at(b"1\n\nmove 4 -> 500"), Some((3, 11)));
+    }
+}
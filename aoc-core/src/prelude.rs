@@ -0,0 +1,7 @@
+#[cfg(feature = "mock-server")]
+pub use crate::mock_server::*;
+pub use crate::{
+    answers::*, automaton::*, client::*, diagnostic::*, digits::*, error::*, grid::*, input::*,
+    modular::*, parsing::*, range_set::*, runner::*, scaffold::*, solver::*, submit::*, timing::*,
+    tracing::*, visualize::*,
+};
@@ -0,0 +1,248 @@
+use std::{collections::BTreeMap, fmt::Debug, ops::RangeInclusive};
+
+/// Integer types usable as [`RangeSet`] bounds
+pub trait RangeBound: Copy + Ord + Debug {
+    /// Next value, `None` on overflow
+    fn succ(self) -> Option<Self>;
+
+    /// Previous value, `None` on underflow
+    fn pred(self) -> Option<Self>;
+
+    /// Number of values in `start..=end`, `start` must not be greater than `end`
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_range_bound {
+    ($($t:ty),*) => {
+        $(
+            impl RangeBound for $t {
+                fn succ(self) -> Option<Self> { self.checked_add(1) }
+
+                fn pred(self) -> Option<Self> { self.checked_sub(1) }
+
+                fn count(start: Self, end: Self) -> u128 { (end as i128 - start as i128) as u128 + 1 }
+            }
+        )*
+    };
+}
+
+impl_range_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Set of values stored as sorted, disjoint and non-adjacent inclusive ranges
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RangeSet<T> {
+    /// `start -> end` of every range
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: RangeBound> RangeSet<T> {
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds every value of `range`, merging it with the overlapping or adjacent ranges
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        // Ranges starting past `end + 1` can neither overlap nor touch
+        let upper = end.succ().unwrap_or(end);
+
+        while let Some((&other_start, &other_end)) = self.ranges.range(..=upper).next_back() {
+            if other_end.succ().is_some_and(|after| after < start) {
+                break;
+            }
+
+            self.ranges.remove(&other_start);
+            start = start.min(other_start);
+            end = end.max(other_end);
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    /// Removes every value of `range`, splitting the ranges it cuts through
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        let overlapping = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|(_, other_end)| **other_end >= start)
+            .map(|(other_start, other_end)| (*other_start, *other_end))
+            .collect::<Vec<_>>();
+
+        for (other_start, other_end) in overlapping {
+            self.ranges.remove(&other_start);
+
+            if other_start < start
+                && let Some(before) = start.pred()
+            {
+                self.ranges.insert(other_start, before);
+            }
+
+            if other_end > end
+                && let Some(after) = end.succ()
+            {
+                self.ranges.insert(after, other_end);
+            }
+        }
+    }
+
+    /// O(log n) membership test
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, end)| *end >= value)
+    }
+
+    /// Total number of values in the set
+    pub fn covered(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|(start, end)| T::count(*start, *end))
+            .sum()
+    }
+
+    /// Number of disjoint ranges
+    pub fn range_count(&self) -> usize { self.ranges.len() }
+
+    pub fn is_empty(&self) -> bool { self.ranges.is_empty() }
+
+    /// Disjoint ranges in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .iter()
+            .map(|(start, end)| RangeInclusive::new(*start, *end))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+
+        return union;
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let mut lhs = self.iter().peekable();
+        let mut rhs = other.iter().peekable();
+
+        while let (Some(a), Some(b)) = (lhs.peek(), rhs.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+
+            if start <= end {
+                intersection.ranges.insert(start, end);
+            }
+
+            // Drop whichever range ends first, the other one may still overlap the next
+            if a.end() < b.end() {
+                lhs.next();
+            } else {
+                rhs.next();
+            }
+        }
+
+        return intersection;
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+
+        for range in other.iter() {
+            difference.remove(range);
+        }
+
+        return difference;
+    }
+}
+
+impl<T: RangeBound> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: RangeBound> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+
+        return set;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> RangeSet<i32> { ranges.iter().cloned().collect() }
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<RangeInclusive<i32>> { set.iter().collect() }
+
+    #[test]
+    fn insert_merges() {
+        let mut set = set(&[10..=14, 3..=5, 16..=20, 12..=18, 6..=7]);
+        set.insert(RangeInclusive::new(30, 29));
+
+        assert_eq!(ranges(&set), [3..=7, 10..=20]);
+        assert_eq!(set.covered(), 16);
+        assert!(set.contains(7));
+        assert!(!set.contains(8));
+        assert!(!set.contains(2));
+    }
+
+    #[test]
+    fn insert_at_bounds() {
+        let set = [u8::MAX..=u8::MAX, 0..=0, 1..=254]
+            .into_iter()
+            .collect::<RangeSet<u8>>();
+
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [RangeInclusive::new(0, u8::MAX)]
+        );
+        assert_eq!(set.covered(), 256);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = set(&[0..=10, 20..=30]);
+        set.remove(5..=22);
+
+        assert_eq!(ranges(&set), [0..=4, 23..=30]);
+
+        set.remove(0..=0);
+        set.remove(30..=40);
+        assert_eq!(ranges(&set), [1..=4, 23..=29]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25, 40..=50]);
+
+        assert_eq!(ranges(&a.union(&b)), [0..=30, 40..=50]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19, 40..=50]);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display, ops::RangeInclusive, str::FromStr, time::Instant};
+
+/// Type-erased entry of the day registry, see [`Registry`]
+#[derive(Clone, Copy, Debug)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Only set with the `embed-inputs` feature, see [`crate::embedded_input`]
+    pub embedded_input: Option<&'static str>,
+    pub run: fn(&str, Option<Part>) -> Result<(Report, Timings)>,
+    pub visualize: fn(&str) -> Result<Option<Vec<Frame>>>,
+}
+
+impl Day {
+    pub const fn new<S: Solver>(embedded_input: Option<&'static str>) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            embedded_input,
+            run: run::<S>,
+            visualize: visualize::<S>,
+        }
+    }
+}
+
+/// Answers of a single run, a part is `None` when it was not selected
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default, Serialize, Deserialize)]
+pub struct Report {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<String>,
+}
+
+impl Report {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Option<String>) {
+        match part {
+            Part::One => self.part_1 = answer,
+            Part::Two => self.part_2 = answer,
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answers = [("part_1", &self.part_1), ("part_2", &self.part_2)]
+            .into_iter()
+            .filter_map(|(name, answer)| Some(format!("{name}: ({})", answer.as_ref()?)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "Solution: [{answers}]")
+    }
+}
+
+/// See [`Solver::visualize`]
+pub fn visualize<S: Solver>(text_input: &str) -> Result<Option<Vec<Frame>>> {
+    return Ok(S::visualize(&S::parse(text_input)?));
+}
+
+#[instrument(name = "run", skip_all, fields(year = S::YEAR, day = S::DAY))]
+pub fn run<S: Solver>(text_input: &str, part: Option<Part>) -> Result<(Report, Timings)> {
+    log_input(text_input);
+
+    let mut report = Report::default();
+    let mut timings = Timings::default();
+
+    let start = Instant::now();
+    let parsed = parse::<S>(text_input)?;
+    timings.parse = start.elapsed();
+
+    for selected in Part::ALL
+        .into_iter()
+        .filter(|selected| part.is_none_or(|part| part == *selected))
+    {
+        let start = Instant::now();
+        let answer = solve_part::<S>(&parsed, selected)?;
+        timings.set(selected, Some(start.elapsed()));
+
+        report.set(selected, Some(answer.to_string()));
+    }
+
+    return Ok((report, timings));
+}
+
+#[instrument(name = "parse", skip_all)]
+fn parse<S: Solver>(text_input: &str) -> Result<S::Parsed<'_>> { S::parse(text_input) }
+
+#[instrument(name = "part", skip_all, fields(part = %part))]
+fn solve_part<S: Solver>(parsed: &S::Parsed<'_>, part: Part) -> Result<S::Answer> {
+    S::solve_part(parsed, part)
+}
+
+/// Runs a day `runs` times (at least once), the answers are taken from the first run
+pub fn bench(day: &Day, text_input: &str, part: Option<Part>, runs: usize) -> Result<DayTimings> {
+    let (report, first) = (day.run)(text_input, part)?;
+    let mut timings = vec![first];
+
+    for _ in 1..runs {
+        timings.push((day.run)(text_input, part)?.1);
+    }
+
+    return Ok(DayTimings::new(day, report, &timings).expect("There is at least one run"));
+}
+
+/// Days of every year crate, keyed by (year, day)
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), &'static Day>,
+}
+
+impl Registry {
+    /// Registers the `DAYS` of each year crate, a (year, day) can only be registered once
+    pub fn new(years: &[&'static [Day]]) -> Result<Self> {
+        let mut days = BTreeMap::new();
+
+        for day in years.iter().copied().flatten() {
+            if days.insert((day.year, day.day), day).is_some() {
+                return Err(format!("Day {}/{} is registered twice", day.year, day.day).into());
+            }
+        }
+
+        return Ok(Self { days });
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&'static Day> {
+        self.days.get(&(year, day)).copied()
+    }
+
+    pub fn latest_year(&self) -> Option<u16> {
+        self.days.last_key_value().map(|((year, _), _)| *year)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static Day> + '_ { self.days.values().copied() }
+
+    /// Registered days matching any of the selections, in (year, day) order
+    pub fn select(&self, selections: &[DaySelection]) -> Vec<&'static Day> {
+        let latest_year = self.latest_year().unwrap_or_default();
+
+        return self
+            .iter()
+            .filter(|day| {
+                selections
+                    .iter()
+                    .any(|selection| selection.contains(day, latest_year))
+            })
+            .collect();
+    }
+}
+
+/// Days picked on the command line: `all`, or `5`, `1..=5`, `1..5` and `all` prefixed by the
+/// year like `2025/5`. Without a year the days are taken from the latest registered year
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum DaySelection {
+    All,
+    Days {
+        year: Option<u16>,
+        days: RangeInclusive<u8>,
+    },
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: &Day, latest_year: u16) -> bool {
+        match self {
+            Self::All => true,
+            Self::Days { year, days } => {
+                year.unwrap_or(latest_year) == day.year && days.contains(&day.day)
+            }
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+
+        let (year, days) = match s.split_once('/') {
+            Some((year, days)) => (Some(year.parse()?), days),
+            None => (None, s),
+        };
+
+        if days.eq_ignore_ascii_case("all") {
+            return Ok(Self::Days {
+                year,
+                days: 1..=u8::MAX,
+            });
+        }
+
+        if let Some((start, end)) = days.split_once("..=") {
+            return Ok(Self::Days {
+                year,
+                days: start.parse()?..=end.parse()?,
+            });
+        }
+
+        if let Some((start, end)) = days.split_once("..") {
+            let end = end.parse::<u8>()?;
+            let Some(end) = end.checked_sub(1) else {
+                return Err(format!("Empty day range: {s}").into());
+            };
+
+            return Ok(Self::Days {
+                year,
+                days: start.parse()?..=end,
+            });
+        }
+
+        let day = days.parse()?;
+        return Ok(Self::Days {
+            year,
+            days: day..=day,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Stub<const YEAR: u16, const DAY: u8>;
+
+    impl<const YEAR: u16, const DAY: u8> Solver for Stub<YEAR, DAY> {
+        const YEAR: u16 = YEAR;
+        const DAY: u8 = DAY;
+
+        type Answer = u8;
+        type Parsed<'i> = ();
+
+        fn parse(_text_input: &str) -> Result<Self::Parsed<'_>> { Ok(()) }
+
+        fn part_1(_parsed: &Self::Parsed<'_>) -> Result<Self::Answer> { Ok(DAY) }
+
+        fn part_2(_parsed: &Self::Parsed<'_>) -> Result<Self::Answer> { Ok(DAY) }
+    }
+
+    const OLD: &[Day] = &[
+        Day::new::<Stub<2024, 1>>(None),
+        Day::new::<Stub<2024, 2>>(None),
+    ];
+    const NEW: &[Day] = &[
+        Day::new::<Stub<2025, 1>>(None),
+        Day::new::<Stub<2025, 3>>(None),
+    ];
+
+    fn select(registry: &Registry, selections: &[&str]) -> Result<Vec<(u16, u8)>> {
+        let selections = selections
+            .iter()
+            .map(|selection| selection.parse())
+            .collect::<Result<Vec<_>>>()?;
+
+        return Ok(registry
+            .select(&selections)
+            .into_iter()
+            .map(|day| (day.year, day.day))
+            .collect());
+    }
+
+    #[test]
+    fn selections() -> Result {
+        let registry = Registry::new(&[NEW, OLD])?;
+
+        assert_eq!(registry.latest_year(), Some(2025));
+        assert_eq!(registry.get(2024, 2).map(|day| day.day), Some(2));
+        assert_eq!(select(&registry, &["all"])?.len(), 4);
+        assert_eq!(select(&registry, &["1..4"])?, [(2025, 1), (2025, 3)]);
+        assert_eq!(select(&registry, &["2024/2", "3"])?, [(2024, 2), (2025, 3)]);
+        assert_eq!(select(&registry, &["2024/all"])?, [(2024, 1), (2024, 2)]);
+        assert_eq!(select(&registry, &["2024/2..=5"])?, [(2024, 2)]);
+        assert!("2024/1..0".parse::<DaySelection>().is_err());
+        assert!("x/1".parse::<DaySelection>().is_err());
+
+        assert!(Registry::new(&[OLD, OLD]).is_err());
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,222 @@
+use crate::prelude::*;
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+/// Files of a new day under a crate root, see [`scaffold_day`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Scaffold {
+    pub year: u16,
+    pub day: u8,
+    pub module: PathBuf,
+    pub example: PathBuf,
+    pub input: PathBuf,
+    /// `days/mod.rs`, where the day gets registered
+    pub registry: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(crate_dir: &Path, year: u16, day: u8) -> Self {
+        let days = crate_dir.join("src/days");
+        let inputs = crate_dir.join("inputs");
+
+        return Self {
+            year,
+            day,
+            module: days.join(format!("day{day:02}.rs")),
+            example: inputs.join(example_file_name(year, day)),
+            input: inputs.join(input_file_name(year, day)),
+            registry: days.join("mod.rs"),
+        };
+    }
+
+    pub fn module_name(&self) -> String { format!("day{:02}", self.day) }
+
+    pub fn source(&self) -> String {
+        DAY_TEMPLATE
+            .replace("{{YEAR}}", &self.year.to_string())
+            .replace("{{DAY_PADDED}}", &format!("{:02}", self.day))
+            .replace("{{DAY}}", &self.day.to_string())
+    }
+
+    /// `days/mod.rs` with the `pub mod` declaration and the `DAYS` entry of the day inserted
+    /// in day order
+    pub fn register(&self, registry: &str) -> Result<String> {
+        let module = self.module_name();
+        let declaration = format!("pub mod {module};");
+        let entry = format!(
+            "    Day::new::<{module}::Day{:02}>({module}::INPUT),",
+            self.day
+        );
+
+        let mut lines = registry.lines().map(str::to_owned).collect::<Vec<_>>();
+
+        if lines.contains(&declaration) {
+            return Err(format!("{module} is already registered").into());
+        }
+
+        let Some(days_end) = lines.iter().rposition(|line| line == "];") else {
+            return Err("The DAYS registry should end with `];`".into());
+        };
+        insert_sorted(&mut lines, entry, "    Day::new::<", days_end);
+
+        let declarations_end = lines
+            .iter()
+            .rposition(|line| line.starts_with("pub mod "))
+            .map_or(0, |last| last + 1);
+        insert_sorted(&mut lines, declaration, "pub mod ", declarations_end);
+
+        return Ok(lines.join("\n") + "\n");
+    }
+
+    /// Writes every file, failing before touching anything if one of them already exists
+    pub fn write(&self) -> Result<Vec<PathBuf>> {
+        if let Some(existing) = [&self.module, &self.example, &self.input]
+            .into_iter()
+            .find(|path| path.exists())
+        {
+            return Err(
+                format!("{} already exists, not overwriting it", existing.display()).into(),
+            );
+        }
+
+        let registry = self.register(&std::fs::read_to_string(&self.registry)?)?;
+
+        if let Some(inputs) = self.example.parent() {
+            std::fs::create_dir_all(inputs)?;
+        }
+
+        create_new(&self.module, &self.source())?;
+        create_new(&self.example, "")?;
+        create_new(&self.input, "")?;
+        std::fs::write(&self.registry, registry)?;
+
+        return Ok(vec![
+            self.module.clone(),
+            self.example.clone(),
+            self.input.clone(),
+            self.registry.clone(),
+        ]);
+    }
+}
+
+/// Generates the module, the empty inputs and the registration of `day` under `crate_dir`,
+/// usually [`year_dir`]
+pub fn scaffold_day(crate_dir: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {day} (expected 1 to 25)").into());
+    }
+
+    return Scaffold::new(crate_dir, year, day).write();
+}
+
+/// Inserts `line` among the lines starting with `prefix` before `end`, keeping them sorted
+fn insert_sorted(lines: &mut Vec<String>, line: String, prefix: &str, end: usize) {
+    let index = lines[..end]
+        .iter()
+        .rposition(|other| other.starts_with(prefix) && *other < line)
+        .map_or_else(
+            || {
+                lines[..end]
+                    .iter()
+                    .position(|other| other.starts_with(prefix))
+                    .unwrap_or(end)
+            },
+            |before| before + 1,
+        );
+
+    lines.insert(index, line);
+}
+
+fn create_new(path: &Path, contents: &str) -> Result {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(contents.as_bytes())?;
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+use aoc_core::runner::Day;
+
+pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(day01::INPUT),
+    Day::new::<day03::Day03>(day03::INPUT),
+];
+";
+
+    #[test]
+    fn registers_in_order() -> Result {
+        let registry = Scaffold::new(Path::new(""), 2025, 2).register(REGISTRY)?;
+        let registry = Scaffold::new(Path::new(""), 2025, 12).register(&registry)?;
+
+        assert_eq!(
+            registry,
+            "\
+use aoc_core::runner::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day12;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(day01::INPUT),
+    Day::new::<day02::Day02>(day02::INPUT),
+    Day::new::<day03::Day03>(day03::INPUT),
+    Day::new::<day12::Day12>(day12::INPUT),
+];
+"
+        );
+        assert!(
+            Scaffold::new(Path::new(""), 2025, 3)
+                .register(REGISTRY)
+                .is_err()
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn writes_files_once() -> Result {
+        let dir = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/days"))?;
+        std::fs::write(dir.join("src/days/mod.rs"), REGISTRY)?;
+
+        let written = scaffold_day(&dir, 2025, 7)?;
+        assert_eq!(written.len(), 4);
+
+        let source = std::fs::read_to_string(dir.join("src/days/day07.rs"))?;
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("const DAY: u8 = 7;"));
+        assert!(source.contains("\"../../inputs/2025_07_input_test.txt\""));
+        assert!(source.contains("{ Ok(0) }"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("inputs/2025_07_input.txt"))?,
+            ""
+        );
+
+        // Nothing is written a second time, even with the registration removed
+        std::fs::write(dir.join("src/days/mod.rs"), REGISTRY)?;
+        assert!(scaffold_day(&dir, 2025, 7).is_err());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("src/days/mod.rs"))?,
+            REGISTRY
+        );
+        assert!(scaffold_day(&dir, 2025, 26).is_err());
+
+        std::fs::remove_dir_all(dir)?;
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+use derive_more::Display;
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Display)]
+#[display("Solution: [part_1: ({part_1}), part_2: ({part_2})]")]
+pub struct Solution<T = u64> {
+    pub part_1: T,
+    pub part_2: T,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Display)]
+pub enum Part {
+    #[display("1")]
+    One,
+    #[display("2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Invalid part: {s} (expected 1 or 2)").into()),
+        }
+    }
+}
+
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Puzzle input after the parse step, may borrow from the raw text
+    type Parsed<'i>;
+    type Answer: Display;
+
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>>;
+
+    /// Fails when the parsed input can't be solved for this part, e.g. when it is too short
+    fn part_1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer>;
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer>;
+
+    fn solve_part(parsed: &Self::Parsed<'_>, part: Part) -> Result<Self::Answer> {
+        match part {
+            Part::One => Self::part_1(parsed),
+            Part::Two => Self::part_2(parsed),
+        }
+    }
+
+    /// Frames of the day's simulation for `--visualize`, `None` when there is nothing to show
+    fn visualize(_parsed: &Self::Parsed<'_>) -> Option<Vec<Frame>> { None }
+
+    fn solve(text_input: &str) -> Result<Solution<Self::Answer>> {
+        log_input(text_input);
+
+        let parsed = Self::parse(text_input)?;
+
+        return Ok(Solution {
+            part_1: Self::part_1(&parsed)?,
+            part_2: Self::part_2(&parsed)?,
+        });
+    }
+}
@@ -0,0 +1,446 @@
+use crate::prelude::*;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Environment variable overriding [`DEFAULT_HISTORY_FILE`]
+pub const HISTORY_FILE_ENV: &str = "AOC_HISTORY_FILE";
+pub const DEFAULT_HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../submissions.toml");
+
+/// Verdict of the puzzle server on a submitted answer
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Display, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    #[display("correct")]
+    Correct,
+    #[display("too high")]
+    TooHigh,
+    #[display("too low")]
+    TooLow,
+    /// Wrong without a hint
+    #[display("wrong")]
+    Wrong,
+    /// Not judged, the previous answer was submitted too recently
+    #[display("too recent")]
+    TooRecent,
+    /// Not judged, the part is already solved or still locked
+    #[display("wrong level")]
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Whether the answer itself was judged, and so is worth remembering
+    pub const fn is_judged(self) -> bool { !matches!(self, Self::TooRecent | Self::WrongLevel) }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct SubmitResponse {
+    pub outcome: Outcome,
+    /// Delay before the server accepts another answer
+    pub wait: Option<Duration>,
+}
+
+impl SubmitResponse {
+    /// Reads the `<article>` of an answer page
+    pub fn parse(html: &str) -> Result<Self> {
+        let article = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| Some(rest.split_once("</article>")?.0))
+            .unwrap_or(html);
+        let text = html_text(article);
+
+        let outcome = if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            match () {
+                _ if text.contains("too high") => Outcome::TooHigh,
+                _ if text.contains("too low") => Outcome::TooLow,
+                _ => Outcome::Wrong,
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::TooRecent
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return Err(format!("Unrecognized answer page: {}", text.trim()).into());
+        };
+
+        return Ok(Self {
+            outcome,
+            wait: parse_wait(&text),
+        });
+    }
+}
+
+/// `You have 1m 30s left to wait` or `Please wait 5 minutes before trying again`
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((_, rest)) = text.split_once("You have ")
+        && let Some((left, _)) = rest.split_once(" left to wait")
+    {
+        let seconds = left
+            .split_whitespace()
+            .map(|amount| {
+                let unit = amount.chars().last()?;
+                let value = amount[..amount.len() - unit.len_utf8()]
+                    .parse::<u64>()
+                    .ok()?;
+
+                return match unit {
+                    'h' => Some(value * 3600),
+                    'm' => Some(value * 60),
+                    's' => Some(value),
+                    _ => None,
+                };
+            })
+            .sum::<Option<u64>>()?;
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let text = text.to_lowercase();
+    let (_, rest) = text.split_once("please wait ")?;
+    let (amount, _) = rest.split_once(" minute")?;
+
+    let minutes = match amount {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+
+    return Some(Duration::from_secs(minutes * 60));
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Judged guesses of both parts of a day
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default, Serialize, Deserialize)]
+pub struct Guesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_2: Vec<Guess>,
+}
+
+impl Guesses {
+    pub fn get(&self, part: Part) -> &[Guess] {
+        match part {
+            Part::One => &self.part_1,
+            Part::Two => &self.part_2,
+        }
+    }
+
+    pub fn get_mut(&mut self, part: Part) -> &mut Vec<Guess> {
+        match part {
+            Part::One => &mut self.part_1,
+            Part::Two => &mut self.part_2,
+        }
+    }
+}
+
+/// Every judged answer keyed by year, then day, and when the server accepts answers again:
+///
+/// ```toml
+/// wait_until = 1764567000
+///
+/// [[2025.1.part_1]]
+/// answer = "1200"
+/// outcome = "too_high"
+///
+/// [[2025.1.part_1]]
+/// answer = "1150"
+/// outcome = "correct"
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    /// Unix time in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(flatten)]
+    pub years: BTreeMap<String, BTreeMap<String, Guesses>>,
+}
+
+impl History {
+    pub fn path(path: Option<PathBuf>) -> PathBuf {
+        path.or_else(|| std::env::var_os(HISTORY_FILE_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY_FILE))
+    }
+
+    /// A missing file is an empty history
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        return Ok(toml::from_str(&std::fs::read_to_string(path)?)?);
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result {
+        std::fs::write(path, toml::to_string(self)?)?;
+        return Ok(());
+    }
+
+    pub fn guesses(&self, year: u16, day: u8, part: Part) -> &[Guess] {
+        self.years
+            .get(&year.to_string())
+            .and_then(|days| days.get(&day.to_string()))
+            .map_or(&[], |guesses| guesses.get(part))
+    }
+
+    /// Refuses `answer` when the part is solved, when it was already judged, when it is not
+    /// between the known too low and too high answers, or while the server asks to wait
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Result {
+        let refuse = |reason: String| {
+            Err(AocError::Refused {
+                answer: answer.to_owned(),
+                reason,
+            })
+        };
+        let guesses = self.guesses(year, day, part);
+
+        if let Some(correct) = guesses
+            .iter()
+            .find(|guess| guess.outcome == Outcome::Correct)
+        {
+            return refuse(format!(
+                "part {part} is already solved with {}",
+                correct.answer
+            ));
+        }
+
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            return refuse(format!("it was already judged {}", guess.outcome));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                guesses
+                    .iter()
+                    .filter(move |guess| guess.outcome == outcome)
+                    .filter_map(|guess| guess.answer.parse::<i128>().ok())
+            };
+
+            if let Some(high) = bound(Outcome::TooHigh).min()
+                && value >= high
+            {
+                return refuse(format!("{high} is already too high"));
+            }
+
+            if let Some(low) = bound(Outcome::TooLow).max()
+                && value <= low
+            {
+                return refuse(format!("{low} is already too low"));
+            }
+        }
+
+        if let Some(wait_until) = self.wait_until
+            && wait_until > now
+        {
+            return refuse(format!(
+                "the server accepts answers again in {}s",
+                wait_until - now
+            ));
+        }
+
+        return Ok(());
+    }
+
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        response: &SubmitResponse,
+        now: u64,
+    ) {
+        self.wait_until = response.wait.map(|wait| now + wait.as_secs());
+
+        if response.outcome.is_judged() {
+            self.years
+                .entry(year.to_string())
+                .or_default()
+                .entry(day.to_string())
+                .or_default()
+                .get_mut(part)
+                .push(Guess {
+                    answer: answer.to_owned(),
+                    outcome: response.outcome,
+                });
+        }
+    }
+
+    /// [`History::check`], then submits `answer` and records the response
+    pub fn submit(
+        &mut self,
+        client: &Client,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<SubmitResponse> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        self.check(year, day, part, answer, now)?;
+
+        let response = client.submit(year, day, part, answer)?;
+        self.record(year, day, part, answer, &response, now);
+
+        return Ok(response);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses() -> Result {
+        let parse = |text: &str| {
+            SubmitResponse::parse(&format!("<main><article><p>{text}</p></article></main>"))
+        };
+
+        assert_eq!(
+            parse("That's the right answer!  You are one gold star closer.")?,
+            SubmitResponse {
+                outcome: Outcome::Correct,
+                wait: None,
+            }
+        );
+        assert_eq!(
+            parse(
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+            )?,
+            SubmitResponse {
+                outcome: Outcome::TooHigh,
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            parse("That's not the right answer.  Please wait 5 minutes before trying again.")?.wait,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 7s left to wait."
+            )?,
+            SubmitResponse {
+                outcome: Outcome::TooRecent,
+                wait: Some(Duration::from_secs(67)),
+            }
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?")?
+                .outcome,
+            Outcome::WrongLevel
+        );
+        assert!(parse("Something else").is_err());
+
+        return Ok(());
+    }
+
+    #[test]
+    fn refusals() -> Result {
+        let mut history = History::default();
+        let judged = |outcome| SubmitResponse {
+            outcome,
+            wait: Some(Duration::from_secs(60)),
+        };
+
+        history.record(2025, 1, Part::One, "100", &judged(Outcome::TooHigh), 0);
+        history.record(2025, 1, Part::One, "10", &judged(Outcome::TooLow), 0);
+        history.record(2025, 1, Part::One, "abc", &judged(Outcome::Wrong), 0);
+
+        assert!(history.check(2025, 1, Part::One, "50", 30).is_err());
+        assert!(history.check(2025, 1, Part::One, "50", 60).is_ok());
+        for answer in ["100", "150", "10", "-5", "abc"] {
+            assert!(matches!(
+                history.check(2025, 1, Part::One, answer, 60),
+                Err(AocError::Refused { .. })
+            ));
+        }
+        assert!(history.check(2025, 1, Part::Two, "150", 60).is_ok());
+
+        history.record(2025, 1, Part::One, "42", &judged(Outcome::TooRecent), 60);
+        assert_eq!(history.guesses(2025, 1, Part::One).len(), 3);
+
+        history.record(2025, 1, Part::One, "42", &judged(Outcome::Correct), 200);
+        assert!(history.check(2025, 1, Part::One, "43", 300).is_err());
+
+        let reloaded: History = toml::from_str(&toml::to_string(&history)?)?;
+        assert_eq!(reloaded, history);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn submits_to_mock_server() -> Result {
+        let server = MockServer::start("127.0.0.1:0", MockPuzzles::default())?;
+        let client = Client::new(Config {
+            session: Some(MOCK_SESSION.to_owned()),
+            base_url: Some(server.url().to_owned()),
+            cache_dir: None,
+        });
+
+        let mut history = History::default();
+        let response = history.submit(&client, 2025, 1, Part::One, "7")?;
+        assert_eq!(response.outcome, Outcome::TooHigh);
+        assert_eq!(response.wait, Some(Duration::from_secs(60)));
+
+        // Refused locally, without reaching the server
+        assert!(history.submit(&client, 2025, 1, Part::One, "8").is_err());
+        assert!(history.submit(&client, 2025, 1, Part::One, "1").is_err());
+        assert_eq!(server.requests(), 1);
+
+        // A fresh history does not know about the wait, the server does
+        let mut fresh = History::default();
+        let response = fresh.submit(&client, 2025, 1, Part::One, "1")?;
+        assert_eq!(response.outcome, Outcome::TooRecent);
+        assert!(
+            response
+                .wait
+                .is_some_and(|wait| wait <= Duration::from_secs(60))
+        );
+        assert!(fresh.guesses(2025, 1, Part::One).is_empty());
+
+        let server = MockServer::start(
+            "127.0.0.1:0",
+            MockPuzzles {
+                cooldown: Duration::ZERO,
+                ..MockPuzzles::default()
+            },
+        )?;
+        let client = Client::new(Config {
+            session: Some(MOCK_SESSION.to_owned()),
+            base_url: Some(server.url().to_owned()),
+            cache_dir: None,
+        });
+
+        let mut history = History::default();
+        assert_eq!(
+            history.submit(&client, 2025, 1, Part::Two, "5")?.outcome,
+            Outcome::Correct
+        );
+        assert_eq!(
+            History::default()
+                .submit(&client, 2025, 1, Part::Two, "5")?
+                .outcome,
+            Outcome::WrongLevel
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::prelude::*;
+use serde::{Serialize, Serializer};
+use std::{fmt::Display, time::Duration};
+
+/// Wall-clock time of each step of a single run, a part is `None` when it was not selected
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn get(&self, part: Part) -> Option<Duration> {
+        match part {
+            Part::One => self.part_1,
+            Part::Two => self.part_2,
+        }
+    }
+
+    pub fn set(&mut self, part: Part, duration: Option<Duration>) {
+        match part {
+            Part::One => self.part_1 = duration,
+            Part::Two => self.part_2 = duration,
+        }
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timings: [parse: {:?}", self.parse)?;
+
+        for part in Part::ALL {
+            if let Some(duration) = self.get(part) {
+                write!(f, ", part_{part}: {duration:?}")?;
+            }
+        }
+
+        write!(f, "]")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "serialize_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "serialize_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "serialize_nanos")]
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: impl IntoIterator<Item = Duration>) -> Option<Self> {
+        let mut samples = samples.into_iter().collect::<Vec<_>>();
+
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        return Some(Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        });
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+/// Timings of one day over one or more runs, exported as JSON by the runner
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub answers: Report,
+    pub parse: Stats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Stats>,
+}
+
+impl DayTimings {
+    pub fn new(day: &Day, answers: Report, timings: &[Timings]) -> Option<Self> {
+        Some(Self {
+            year: day.year,
+            day: day.day,
+            runs: timings.len(),
+            answers,
+            parse: Stats::from_samples(timings.iter().map(|timing| timing.parse))?,
+            part_1: Stats::from_samples(timings.iter().filter_map(|timing| timing.part_1)),
+            part_2: Stats::from_samples(timings.iter().filter_map(|timing| timing.part_2)),
+        })
+    }
+}
+
+impl Display for DayTimings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bench ({} runs): [parse: ({})", self.runs, self.parse)?;
+
+        for (name, stats) in [("part_1", &self.part_1), ("part_2", &self.part_2)] {
+            if let Some(stats) = stats {
+                write!(f, ", {name}: ({stats})")?;
+            }
+        }
+
+        write!(f, "]")
+    }
+}
+
+fn serialize_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+
+        assert_eq!(Stats::from_samples([]), None);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::error::{AocError, Result};
+use std::{
+    fs::File,
+    io::{BufWriter, IsTerminal},
+    path::{Path, PathBuf},
+};
+pub use tracing::*;
+use tracing_appender::{
+    non_blocking::WorkerGuard,
+    rolling::{RollingFileAppender, Rotation},
+};
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::{
+    EnvFilter, Layer, Registry,
+    filter::{Directive, FilterExt, LevelFilter},
+    fmt,
+    fmt::{MakeWriter, format::FmtSpan},
+    layer::SubscriberExt,
+    registry::LookupSpan,
+};
+
+/// Environment variable with `tracing-subscriber` filter directives, see [`TracingConfig::level`]
+pub const LOG_FILTER_ENV: &str = "RUST_LOG";
+
+/// Output of the log events
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub enum LogFormat {
+    /// Human readable, one line per event
+    #[default]
+    Compact,
+    /// One JSON object per event, with the fields of the enclosing spans
+    Json,
+}
+
+/// How often a new log file is started
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub enum LogRotation {
+    #[default]
+    Never,
+    Minutely,
+    Hourly,
+    Daily,
+}
+
+/// File the log events are written to, see [`TracingConfig::log_file`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct LogFile {
+    /// With a [`LogRotation`], the date and time of each file are appended to its name
+    pub path: PathBuf,
+    pub rotation: LogRotation,
+    /// Older rotated files are deleted
+    pub max_files: Option<usize>,
+}
+
+impl LogFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            rotation: LogRotation::Never,
+            max_files: None,
+        }
+    }
+
+    fn appender(&self) -> Result<RollingFileAppender> {
+        let Some(prefix) = self.path.file_name() else {
+            return Err(format!("Log file {} has no file name", self.path.display()).into());
+        };
+        let dir = self
+            .path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        // The appender lists the directory to prune the old files before creating it
+        std::fs::create_dir_all(dir)?;
+        let rotation = match self.rotation {
+            LogRotation::Never => Rotation::NEVER,
+            LogRotation::Minutely => Rotation::MINUTELY,
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Daily => Rotation::DAILY,
+        };
+
+        let mut builder = RollingFileAppender::builder()
+            .rotation(rotation)
+            .filename_prefix(prefix.to_string_lossy());
+        if let Some(max_files) = self.max_files {
+            builder = builder.max_log_files(max_files);
+        }
+
+        return builder.build(dir).map_err(|error| {
+            format!("Can't write the log file {}: {error}", self.path.display()).into()
+        });
+    }
+}
+
+/// Global subscriber setup, see [`init_tracing`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct TracingConfig {
+    /// Maximum level of the events, [`LOG_FILTER_ENV`] takes precedence over it
+    pub level: Level,
+    /// `target=level` directives, e.g. `aoc2025::days::day04=warn`. They take precedence over
+    /// [`TracingConfig::level`] and [`LOG_FILTER_ENV`]
+    pub filters: Vec<String>,
+    /// Colors of the terminal output, the log file never has any
+    pub ansi: bool,
+    pub format: LogFormat,
+    /// Logs every span when it closes, with its busy and idle time
+    pub span_timings: bool,
+    /// Writes the events to a file, the terminal then only gets the `info` and more severe ones
+    pub log_file: Option<LogFile>,
+    /// Writes the span stacks of the run to a file in the folded format of `inferno` and
+    /// `flamegraph.pl`. Spans are filtered like the events of the log file
+    pub flame: Option<PathBuf>,
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            filters: vec![],
+            ansi: std::io::stdout().is_terminal(),
+            format: LogFormat::default(),
+            span_timings: false,
+            log_file: None,
+            flame: None,
+        }
+    }
+}
+
+/// Keeps the log file and the flame graph file open, they are flushed when dropped
+#[must_use = "dropping the guard stops writing the log and flame graph files"]
+pub struct TracingGuard {
+    _log_file: Option<WorkerGuard>,
+    _flame: Option<FlushGuard<BufWriter<File>>>,
+}
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+impl TracingConfig {
+    /// The level, then [`LOG_FILTER_ENV`], then the filters: a later directive for the same
+    /// target replaces the earlier one
+    pub fn env_filter(&self) -> Result<EnvFilter> {
+        let env_directives = std::env::var(LOG_FILTER_ENV).unwrap_or_default();
+
+        return env_directives
+            .split(',')
+            .chain(self.filters.iter().map(String::as_str))
+            .map(str::trim)
+            .filter(|directive| !directive.is_empty())
+            .try_fold(
+                EnvFilter::default().add_directive(LevelFilter::from(self.level).into()),
+                |filter, directive| {
+                    let parsed =
+                        directive
+                            .parse::<Directive>()
+                            .map_err(|error| AocError::LogFilter {
+                                directive: directive.to_owned(),
+                                source: error,
+                            })?;
+
+                    return Ok(filter.add_directive(parsed));
+                },
+            );
+    }
+
+    fn fmt_layer<W>(&self, writer: W, ansi: bool) -> BoxedLayer
+    where
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static, {
+        let span_events = match self.span_timings {
+            true => FmtSpan::CLOSE,
+            false => FmtSpan::NONE,
+        };
+
+        match self.format {
+            LogFormat::Compact => fmt::layer()
+                .with_writer(writer)
+                .with_ansi(ansi)
+                .with_target(true)
+                .with_span_events(span_events)
+                .compact()
+                .boxed(),
+            LogFormat::Json => fmt::layer()
+                .with_writer(writer)
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .with_span_events(span_events)
+                .boxed(),
+        }
+    }
+
+    pub fn build(&self) -> Result<(impl Subscriber + Send + Sync + use<>, TracingGuard)> {
+        let mut layers: Vec<BoxedLayer> = vec![];
+
+        let (terminal_filter, log_file_guard) = match &self.log_file {
+            Some(log_file) => {
+                let (writer, guard) = tracing_appender::non_blocking(log_file.appender()?);
+                layers.push(
+                    self.fmt_layer(writer, false)
+                        .with_filter(self.env_filter()?)
+                        .boxed(),
+                );
+
+                (LevelFilter::INFO, Some(guard))
+            }
+            None => (LevelFilter::TRACE, None),
+        };
+
+        layers.push(
+            self.fmt_layer(std::io::stdout, self.ansi)
+                .with_filter(self.env_filter()?.and(terminal_filter))
+                .boxed(),
+        );
+
+        let flame_guard = match &self.flame {
+            Some(path) => {
+                let (layer, guard) = flame_layer(path)?;
+                layers.push(layer.with_filter(self.env_filter()?).boxed());
+                Some(guard)
+            }
+            None => None,
+        };
+
+        return Ok((
+            Registry::default().with(layers),
+            TracingGuard {
+                _log_file: log_file_guard,
+                _flame: flame_guard,
+            },
+        ));
+    }
+}
+
+fn flame_layer<S>(
+    path: &Path,
+) -> Result<(FlameLayer<S, BufWriter<File>>, FlushGuard<BufWriter<File>>)>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>, {
+    let (layer, guard) = FlameLayer::with_file(path)
+        .map_err(|error| format!("Can't write the flame graph to {}: {error}", path.display()))?;
+
+    // Rayon workers show up as a single stack instead of one per thread
+    let layer = layer.with_threads_collapsed(true).with_file_and_line(false);
+
+    return Ok((layer, guard));
+}
+
+/// Installs the global subscriber, only the first call of a process has an effect. Invalid
+/// filters are an [`AocError::LogFilter`]
+pub fn init_tracing(config: &TracingConfig) -> Result<TracingGuard> {
+    let (subscriber, guard) = config.build()?;
+
+    if tracing::subscriber::set_global_default(subscriber).is_err() {
+        debug!("Tracing is already initialized");
+    }
+
+    return Ok(guard);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folded_stacks() -> Result {
+        let path = std::env::temp_dir().join(format!("aoc_flame_{}.folded", std::process::id()));
+        let config = TracingConfig {
+            filters: vec!["quiet=warn".into()],
+            flame: Some(path.clone()),
+            ..TracingConfig::default()
+        };
+        let (subscriber, guard) = config.build()?;
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _outer = info_span!("outer").entered();
+            let sleep = || std::thread::sleep(std::time::Duration::from_millis(1));
+            info_span!("inner").in_scope(sleep);
+            debug_span!("below_level").in_scope(sleep);
+            info_span!(target: "quiet", "filtered_out").in_scope(sleep);
+        });
+        drop(guard);
+
+        let folded = std::fs::read_to_string(&path)?;
+        assert!(
+            folded
+                .lines()
+                .any(|line| line.contains("outer;") && line.contains("inner ")),
+            "{folded}"
+        );
+        assert!(
+            !folded.contains("below_level") && !folded.contains("filtered_out"),
+            "{folded}"
+        );
+
+        std::fs::remove_file(path)?;
+
+        return Ok(());
+    }
+
+    #[test]
+    fn log_file_filters() -> Result {
+        let path = std::env::temp_dir().join(format!("aoc_log_{}.log", std::process::id()));
+        let config = TracingConfig {
+            level: Level::DEBUG,
+            filters: vec!["noisy=warn".into()],
+            ansi: true,
+            log_file: Some(LogFile::new(&path)),
+            ..TracingConfig::default()
+        };
+        let (subscriber, guard) = config.build()?;
+
+        tracing::subscriber::with_default(subscriber, || {
+            debug!(target: "quiet", "quiet debug");
+            debug!(target: "noisy", "noisy debug");
+            warn!(target: "noisy", "noisy warning");
+        });
+        drop(guard);
+
+        let log = std::fs::read_to_string(&path)?;
+        assert!(
+            log.contains("quiet debug") && log.contains("noisy warning"),
+            "{log}"
+        );
+        assert!(
+            !log.contains("noisy debug") && !log.contains('\x1b'),
+            "{log}"
+        );
+
+        std::fs::remove_file(path)?;
+
+        return Ok(());
+    }
+
+    #[test]
+    fn invalid_filter() {
+        let config = TracingConfig {
+            filters: vec!["day04=loud".into()],
+            ..TracingConfig::default()
+        };
+
+        assert!(matches!(
+            config.build(),
+            Err(AocError::LogFilter { directive, .. }) if directive == "day04=loud"
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::prelude::*;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub type Rgb = [u8; 3];
+
+/// Color of the cells changed during a frame's step
+pub const HIGHLIGHT: Rgb = [230, 57, 70];
+
+/// How a grid cell is drawn in a [`Frame`]
+pub trait Render {
+    fn glyph(&self) -> char;
+
+    fn color(&self) -> Rgb;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+/// Rendered grid after a simulation step
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Frame {
+    pub step: usize,
+    pub changed: usize,
+    pub pixels: Grid<Pixel>,
+}
+
+impl Frame {
+    /// Renders `grid`, the `changed` cells are drawn in [`HIGHLIGHT`]
+    pub fn capture<T: Render>(step: usize, grid: &Grid<T>, changed: &[Pos]) -> Self {
+        let mut pixels = grid.map(|cell| Pixel {
+            glyph: cell.glyph(),
+            color: cell.color(),
+        });
+
+        for pos in changed {
+            pixels[*pos].color = HIGHLIGHT;
+        }
+
+        return Self {
+            step,
+            changed: changed.len(),
+            pixels,
+        };
+    }
+
+    /// RGB bytes with every cell drawn as a `cell_size` square
+    fn rgb(&self, cell_size: usize) -> (usize, usize, Vec<u8>) {
+        let (width, height) = (
+            self.pixels.width() * cell_size,
+            self.pixels.height() * cell_size,
+        );
+        let mut rgb = Vec::with_capacity(width * height * 3);
+
+        for row in self.pixels.rows() {
+            for _ in 0..cell_size {
+                for pixel in row {
+                    for _ in 0..cell_size {
+                        rgb.extend_from_slice(&pixel.color);
+                    }
+                }
+            }
+        }
+
+        return (width, height, rgb);
+    }
+}
+
+/// Still image formats, one file per frame
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Png => "png",
+        }
+    }
+}
+
+/// Plays the frames in the terminal with 24-bit ANSI colors, waiting `delay` between frames
+pub fn play(frames: &[Frame], delay: Duration, out: &mut impl Write) -> Result {
+    for frame in frames {
+        // Clear the screen and move the cursor to the top left
+        write!(out, "\x1b[2J\x1b[H")?;
+        writeln!(out, "step {}: {} changed", frame.step, frame.changed)?;
+
+        for row in frame.pixels.rows() {
+            for Pixel {
+                glyph,
+                color: [r, g, b],
+            } in row
+            {
+                write!(out, "\x1b[38;2;{r};{g};{b}m{glyph}")?;
+            }
+
+            writeln!(out, "\x1b[0m")?;
+        }
+
+        out.flush()?;
+        std::thread::sleep(delay);
+    }
+
+    return Ok(());
+}
+
+/// Writes every frame to `dir/{stem}_{step}.{extension}`, returns the written paths
+pub fn write_images(
+    frames: &[Frame],
+    format: ImageFormat,
+    cell_size: usize,
+    dir: &Path,
+    stem: &str,
+) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+
+    return frames
+        .iter()
+        .map(|frame| {
+            let path = dir.join(format!("{stem}_{:04}.{}", frame.step, format.extension()));
+            let out = BufWriter::new(File::create(&path)?);
+
+            match format {
+                ImageFormat::Ppm => write_ppm(frame, cell_size, out)?,
+                ImageFormat::Png => write_png(frame, cell_size, out)?,
+            }
+
+            return Ok(path);
+        })
+        .collect();
+}
+
+/// Binary PPM (`P6`)
+pub fn write_ppm(frame: &Frame, cell_size: usize, mut out: impl Write) -> Result {
+    let (width, height, rgb) = frame.rgb(cell_size);
+
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(&rgb)?;
+    out.flush()?;
+
+    return Ok(());
+}
+
+pub fn write_png(frame: &Frame, cell_size: usize, out: impl Write) -> Result {
+    let (width, height, rgb) = frame.rgb(cell_size);
+
+    let mut encoder = png::Encoder::new(out, width as _, height as _);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&rgb)?;
+    writer.finish()?;
+
+    return Ok(());
+}
+
+/// Looping animated GIF, the frames share a palette of at most 256 colors
+pub fn write_gif(frames: &[Frame], cell_size: usize, delay: Duration, out: impl Write) -> Result {
+    let Some(first) = frames.first() else {
+        return Err("No frame to write".into());
+    };
+
+    let mut palette = HashMap::<Rgb, u8>::new();
+    for pixel in frames.iter().flat_map(|frame| frame.pixels.cells()) {
+        let next = palette.len();
+
+        if let Entry::Vacant(entry) = palette.entry(pixel.color) {
+            entry.insert(u8::try_from(next).map_err(|_| "GIF frames use over 256 colors")?);
+        }
+    }
+
+    let mut global_palette = vec![0; palette.len() * 3];
+    for (color, index) in &palette {
+        let index = *index as usize * 3;
+        global_palette[index..index + 3].copy_from_slice(color);
+    }
+
+    let too_large = |_| AocError::from("Grid is too large for a GIF");
+    let width = u16::try_from(first.pixels.width() * cell_size).map_err(too_large)?;
+    let height = u16::try_from(first.pixels.height() * cell_size).map_err(too_large)?;
+
+    let mut encoder = gif::Encoder::new(out, width, height, &global_palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in frames {
+        let (_, _, rgb) = frame.rgb(cell_size);
+        let buffer = rgb
+            .as_chunks::<3>()
+            .0
+            .iter()
+            .map(|color| palette[color])
+            .collect::<Vec<_>>();
+
+        encoder.write_frame(&gif::Frame {
+            width,
+            height,
+            // In hundredths of a second
+            delay: (delay.as_millis() / 10).min(u16::MAX as _) as _,
+            buffer: buffer.into(),
+            ..Default::default()
+        })?;
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl Render for bool {
+        fn glyph(&self) -> char { if *self { '#' } else { '.' } }
+
+        fn color(&self) -> Rgb { if *self { [255; 3] } else { [0; 3] } }
+    }
+
+    fn frame() -> Frame {
+        let mut grid = Grid::new(2, 1, false);
+        grid[(1, 0)] = true;
+
+        return Frame::capture(1, &grid, &[(0, 0)]);
+    }
+
+    #[test]
+    fn ppm() -> Result {
+        let mut out = vec![];
+        write_ppm(&frame(), 1, &mut out)?;
+
+        assert_eq!(out, b"P6\n2 1\n255\n\xe6\x39\x46\xff\xff\xff");
+
+        return Ok(());
+    }
+
+    #[test]
+    fn terminal() -> Result {
+        let mut out = vec![];
+        play(&[frame()], Duration::ZERO, &mut out)?;
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("step 1: 1 changed"));
+        assert!(out.contains("\x1b[38;2;230;57;70m.\x1b[38;2;255;255;255m#\x1b[0m"));
+
+        return Ok(());
+    }
+
+    #[test]
+    fn encoded_images() -> Result {
+        let (mut png, mut gif) = (vec![], vec![]);
+        write_png(&frame(), 2, &mut png)?;
+        write_gif(&[frame(), frame()], 2, Duration::from_millis(100), &mut gif)?;
+
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(gif.starts_with(b"GIF89a"));
+
+        return Ok(());
+    }
+}
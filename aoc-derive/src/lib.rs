@@ -0,0 +1,230 @@
+//! `#[derive(AocParse)]`, see `aoc_core::parsing::AocParse` for the supported attributes
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, LitByteStr, LitStr, parse_macro_input,
+    parse_quote, spanned::Spanned,
+};
+
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Arguments of the `#[aoc(...)]` attributes on one item
+#[derive(Default)]
+struct AocArgs {
+    sep: Option<LitStr>,
+    blocks: bool,
+    prefix: Option<LitStr>,
+}
+
+impl AocArgs {
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut args = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sep") {
+                    args.sep = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("blocks") {
+                    args.blocks = true;
+                } else if meta.path.is_ident("prefix") {
+                    args.prefix = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown `aoc` argument, expected `sep = \"..\"`, `blocks` or `prefix = \"..\"`",
+                    ));
+                }
+
+                return Ok(());
+            })?;
+        }
+
+        return Ok(args);
+    }
+
+    /// Separator between the fields of a struct or variant
+    fn separator(&self) -> Result<Option<LitByteStr>> {
+        match (&self.sep, self.blocks) {
+            (Some(sep), true) => Err(Error::new(
+                sep.span(),
+                "`sep` and `blocks` can't be used together",
+            )),
+            (Some(sep), false) => Ok(Some(LitByteStr::new(sep.value().as_bytes(), sep.span()))),
+            (None, true) => Ok(Some(LitByteStr::new(
+                b"\n\n",
+                proc_macro2::Span::call_site(),
+            ))),
+            (None, false) => Ok(None),
+        }
+    }
+
+    fn reject_prefix(&self) -> Result {
+        match &self.prefix {
+            Some(prefix) => Err(Error::new(
+                prefix.span(),
+                "`prefix` is only allowed on enum variants",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+type Result<T = (), E = Error> = std::result::Result<T, E>;
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+
+    // Every field is parsed, so every type parameter must be parsable too
+    let mut generics = input.generics.clone();
+    for param in input.generics.type_params() {
+        let ident = &param.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#ident: ::aoc_core::parsing::AocParse));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let args = AocArgs::from_attrs(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            args.reject_prefix()?;
+            expand_fields(quote!(Self), &data.fields, args.separator()?, input.span())?
+        }
+        Data::Enum(data) => {
+            if args.sep.is_some() || args.blocks || args.prefix.is_some() {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "enums take `#[aoc(prefix = \"..\")]` on every variant instead",
+                ));
+            }
+
+            let mut prefixes = vec![];
+            let mut arms = vec![];
+
+            for variant in &data.variants {
+                let args = AocArgs::from_attrs(&variant.attrs)?;
+                let Some(prefix) = &args.prefix else {
+                    return Err(Error::new(
+                        variant.ident.span(),
+                        "enum variants need `#[aoc(prefix = \"..\")]`",
+                    ));
+                };
+                let prefix = LitByteStr::new(prefix.value().as_bytes(), prefix.span());
+                let variant_name = &variant.ident;
+
+                let arm = if variant.fields.is_empty() {
+                    quote! {
+                        if bytes == #prefix {
+                            return Ok(Self::#variant_name);
+                        }
+                    }
+                } else {
+                    let variant_body = expand_fields(
+                        quote!(Self::#variant_name),
+                        &variant.fields,
+                        args.separator()?,
+                        variant.span(),
+                    )?;
+
+                    quote! {
+                        if let Some(rest) = bytes.strip_prefix(#prefix) {
+                            let variant = |bytes: &[u8]| -> ::aoc_core::error::Result<Self> {
+                                #variant_body
+                            };
+
+                            return variant(rest).map_err(|error| {
+                                ::aoc_core::parsing::relocate(error, bytes, #prefix.len())
+                            });
+                        }
+                    }
+                };
+
+                prefixes.push(prefix);
+                arms.push(arm);
+            }
+
+            quote! {
+                #(#arms)*
+                Err(::aoc_core::parsing::unknown_prefix(bytes, &[#(#prefixes),*]))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`AocParse` can't be derived for unions",
+            ));
+        }
+    };
+
+    return Ok(quote! {
+        impl #impl_generics ::aoc_core::parsing::AocParse for #name #ty_generics #where_clause {
+            fn aoc_parse(bytes: &[u8]) -> ::aoc_core::error::Result<Self> {
+                #body
+            }
+        }
+    });
+}
+
+/// Splits `bytes` around `separator` and parses one field per piece into `constructor`
+fn expand_fields(
+    constructor: TokenStream2,
+    fields: &Fields,
+    separator: Option<LitByteStr>,
+    span: proc_macro2::Span,
+) -> Result<TokenStream2> {
+    let count = fields.len();
+
+    let split = match (&separator, count) {
+        (_, 0) => return Err(Error::new(span, "`AocParse` needs at least one field")),
+        (Some(separator), _) => {
+            quote!(let fields = ::aoc_core::parsing::split_fields::<#count>(bytes, #separator)?;)
+        }
+        (None, 1) => quote!(let fields = [(0, bytes)];),
+        (None, _) => {
+            return Err(Error::new(
+                span,
+                "several fields need `#[aoc(sep = \"..\")]` or `#[aoc(blocks)]`",
+            ));
+        }
+    };
+
+    let values = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let args = AocArgs::from_attrs(&field.attrs)?;
+            args.reject_prefix()?;
+
+            let value = match args.separator()? {
+                Some(separator) => quote! {
+                    ::aoc_core::parsing::parse_separated_at(bytes, fields[#i], #separator)?
+                },
+                None => quote!(::aoc_core::parsing::parse_at(bytes, fields[#i])?),
+            };
+
+            return Ok(match &field.ident {
+                Some(ident) => quote!(#ident: #value),
+                None => value,
+            });
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(#constructor { #(#values),* }),
+        _ => quote!(#constructor(#(#values),*)),
+    };
+
+    return Ok(quote! {
+        #split
+        Ok(#construct)
+    });
+}
@@ -0,0 +1,132 @@
+use aoc2025::prelude::*;
+use divan::{Bencher, black_box};
+use std::{borrow::Cow, fmt::Display, sync::LazyLock};
+
+/// Environment variable naming a file to write the folded span stacks of every bench to. The
+/// spans are filtered by [`LOG_FILTER_ENV`], e.g. `RUST_LOG=aoc2025::days::day02=debug` to see
+/// how the `range` spans of `day02_strategies::rayon_nested` add up
+const FLAME_FILE_ENV: &str = "AOC_FLAME_FILE";
+
+fn main() {
+    let _guard = std::env::var_os(FLAME_FILE_ENV).map(|path| {
+        init_tracing(&TracingConfig {
+            level: Level::WARN,
+            flame: Some(path.into()),
+            ..TracingConfig::default()
+        })
+        .unwrap()
+    });
+
+    divan::main();
+}
+
+pub struct BenchInput {
+    pub name: &'static str,
+    pub text: Cow<'static, str>,
+}
+
+impl Display for BenchInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(self.name) }
+}
+
+/// Example input, plus the real input when it can be found by [`InputLoader`]. Loaded once per
+/// day into a [`LazyLock`], every bench of the day borrows it
+fn inputs<S: Solver>(example: &'static str, embedded: Option<&'static str>) -> Vec<BenchInput> {
+    let mut inputs = vec![BenchInput {
+        name: "example",
+        text: Cow::Borrowed(example),
+    }];
+
+    match InputLoader::default().load(&Day::new::<S>(embedded)) {
+        Ok(text) => inputs.push(BenchInput { name: "real", text }),
+        Err(error) => eprintln!("Skipping real input of day {}: {error}", S::DAY),
+    }
+
+    return inputs;
+}
+
+macro_rules! bench_day {
+    ($day:ident, $solver:ident) => {
+        mod $day {
+            use super::*;
+            use aoc2025::days::$day::{INPUT, INPUT_TEST, $solver};
+
+            static INPUTS: LazyLock<Vec<BenchInput>> =
+                LazyLock::new(|| super::inputs::<$solver>(INPUT_TEST, INPUT));
+
+            pub(super) fn inputs() -> &'static [BenchInput] { &INPUTS }
+
+            #[divan::bench(args = inputs())]
+            fn parse(bencher: Bencher, input: &BenchInput) {
+                bencher.bench(|| $solver::parse(black_box(&input.text)).unwrap());
+            }
+
+            #[divan::bench(args = inputs())]
+            fn part_1(bencher: Bencher, input: &BenchInput) {
+                let parsed = $solver::parse(&input.text).unwrap();
+                bencher.bench(|| $solver::part_1(black_box(&parsed)));
+            }
+
+            #[divan::bench(args = inputs())]
+            fn part_2(bencher: Bencher, input: &BenchInput) {
+                let parsed = $solver::parse(&input.text).unwrap();
+                bencher.bench(|| $solver::part_2(black_box(&parsed)));
+            }
+        }
+    };
+}
+
+bench_day!(day01, Day01);
+bench_day!(day02, Day02);
+bench_day!(day03, Day03);
+bench_day!(day04, Day04);
+bench_day!(day05, Day05);
+
+/// Nested rayon iteration of `sum_invalid` against a plain sequential scan and the closed form
+mod day02_strategies {
+    use super::*;
+    // Same inputs as the `day02` benches, loaded once for both
+    use super::day02::inputs;
+    use aoc2025::days::day02::{Day02, ProductID, ProductIDRange, sum_invalid};
+
+    fn is_valid<const PART: u8>(product_id: &ProductID) -> bool {
+        match PART {
+            1 => product_id.is_valid_part_1(),
+            _ => product_id.is_valid_part_2(),
+        }
+    }
+
+    fn sum_invalid_sequential(
+        product_id_ranges: &[ProductIDRange],
+        is_valid: impl Fn(&ProductID) -> bool,
+    ) -> u64 {
+        product_id_ranges
+            .iter()
+            .flat_map(|product_id_range| product_id_range.first.0..=product_id_range.last.0)
+            .map(ProductID)
+            .filter(|product_id| !is_valid(product_id))
+            .map(|product_id| *product_id)
+            .sum()
+    }
+
+    #[divan::bench(consts = [1, 2], args = inputs())]
+    fn rayon_nested<const PART: u8>(bencher: Bencher, input: &BenchInput) {
+        let product_id_ranges = Day02::parse(&input.text).unwrap();
+        bencher.bench(|| sum_invalid(black_box(&product_id_ranges), is_valid::<PART>));
+    }
+
+    #[divan::bench(consts = [1, 2], args = inputs())]
+    fn sequential<const PART: u8>(bencher: Bencher, input: &BenchInput) {
+        let product_id_ranges = Day02::parse(&input.text).unwrap();
+        bencher.bench(|| sum_invalid_sequential(black_box(&product_id_ranges), is_valid::<PART>));
+    }
+
+    #[divan::bench(consts = [1, 2], args = inputs())]
+    fn closed_form<const PART: u8>(bencher: Bencher, input: &BenchInput) {
+        let product_id_ranges = Day02::parse(&input.text).unwrap();
+        bencher.bench(|| match PART {
+            1 => Day02::part_1(black_box(&product_id_ranges)),
+            _ => Day02::part_2(black_box(&product_id_ranges)),
+        });
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{embedded_input, prelude::*};
+
+pub const INPUT_TEST: &str = include_str!("../../inputs/2025_01_input_test.txt");
+pub const INPUT: Option<&str> = embedded_input!("2025_01_input.txt");
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Answer = u64;
+    type Parsed<'i> = Vec<Rotation>;
+
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>> { Vec::aoc_parse(text_input.as_bytes()) }
+
+    /// Dial ended perfectly at 0
+    fn part_1(rotations: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut dial = DIAL_START;
+        let mut counter = 0;
+
+        for rotation in rotations {
+            dial = dial.rotate(rotation.delta()).value;
+
+            debug!("Dial: {dial}");
+
+            if dial == Dial::ZERO {
+                counter += 1;
+            }
+        }
+
+        return Ok(counter);
+    }
+
+    /// Dial ended perfectly at 0 OR clicked pass 0
+    fn part_2(rotations: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut dial = DIAL_START;
+        let mut counter = 0;
+
+        for rotation in rotations {
+            let turned = dial.rotate(rotation.delta());
+            dial = turned.value;
+            counter += turned.zero_crossings;
+
+            debug!("Dial: {dial}; zero crossings: {counter}");
+        }
+
+        return Ok(counter);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, AocParse)]
+pub enum Rotation {
+    #[aoc(prefix = "L")]
+    Left(i64),
+    #[aoc(prefix = "R")]
+    Right(i64),
+}
+
+impl Rotation {
+    /// Signed number of clicks, negative towards the left
+    pub const fn delta(self) -> i64 {
+        match self {
+            Self::Left(clicks) => -clicks,
+            Self::Right(clicks) => clicks,
+        }
+    }
+}
+
+pub type Dial = Modular<100>;
+
+pub const DIAL_START: Dial = Dial::new(50);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result {
+        let Solution { part_1, part_2 } = Day01::solve(INPUT_TEST)?;
+        assert_eq!(part_1, 3);
+        assert_eq!(part_2, 6);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Day01::parse("L68\nX30"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day01::parse("R4x"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 3,
+                len: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day01::parse("L1\n\nR2"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,366 @@
+use crate::{embedded_input, prelude::*};
+use derive_more::{Deref, DerefMut};
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::fmt::Display;
+
+pub const INPUT_TEST: &str = include_str!("../../inputs/2025_02_input_test.txt");
+pub const INPUT: Option<&str> = embedded_input!("2025_02_input.txt");
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, AocParse)]
+#[aoc(sep = "-")]
+pub struct ProductIDRange {
+    pub first: ProductID,
+    pub last: ProductID,
+}
+
+impl ProductIDRange {
+    /// Sum of the invalid IDs with a digit block repeated exactly twice, see [`sum_repeated`]
+    #[instrument(level = "debug", skip_all, fields(first = self.first.0, last = self.last.0))]
+    pub fn invalid_sum_part_1(&self) -> u128 {
+        (self.first.digits()..=self.last.digits())
+            .filter(|len| len.is_multiple_of(2))
+            .map(|len| sum_repeated(self, len, len / 2))
+            .sum()
+    }
+
+    /// Sum of the invalid IDs with a digit block repeated at least twice.
+    ///
+    /// IDs with several block sizes (`111111` is `1`, `11` and `111` repeated) are counted once
+    /// by Möbius inversion over the block sizes: for IDs of `len` digits, the sum over every
+    /// proper period is `-Σ μ(len / block_len) × sum_repeated(block_len)` for every proper
+    /// divisor `block_len` of `len`
+    #[instrument(level = "debug", skip_all, fields(first = self.first.0, last = self.last.0))]
+    pub fn invalid_sum_part_2(&self) -> u128 {
+        (self.first.digits()..=self.last.digits())
+            .map(|len| {
+                let signed_sum = (1..len)
+                    .filter(|block_len| len.is_multiple_of(*block_len))
+                    .map(|block_len| {
+                        -mobius(len / block_len) * sum_repeated(self, len, block_len) as i128
+                    })
+                    .sum::<i128>();
+
+                return signed_sum as u128;
+            })
+            .sum()
+    }
+}
+
+/// Sum of the `len` digits IDs inside `range` made of a `block_len` digits block repeated,
+/// that is every `block × (1 + 10^block_len + 10^(2 × block_len) + ...)`
+fn sum_repeated(range: &ProductIDRange, len: u32, block_len: u32) -> u128 {
+    let multiplier = (10_u128.pow(len) - 1) / (10_u128.pow(block_len) - 1);
+
+    let min_block = 10_u128
+        .pow(block_len - 1)
+        .max((range.first.0 as u128).div_ceil(multiplier));
+    let max_block = (10_u128.pow(block_len) - 1).min(range.last.0 as u128 / multiplier);
+
+    if min_block > max_block {
+        return 0;
+    }
+
+    // One of the two factors is even
+    let block_sum = (min_block + max_block) * (max_block - min_block + 1) / 2;
+
+    return block_sum * multiplier;
+}
+
+/// Möbius function, `n` is at most the 20 digits of `u64::MAX`
+const fn mobius(mut n: u32) -> i128 {
+    let mut sign = 1;
+    let mut factor = 2;
+
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            n /= factor;
+
+            if n.is_multiple_of(factor) {
+                return 0;
+            }
+
+            sign = -sign;
+        }
+
+        factor += 1;
+    }
+
+    if n > 1 {
+        sign = -sign;
+    }
+
+    return sign;
+}
+
+impl IntoParallelIterator for ProductIDRange {
+    type Iter = rayon::iter::Map<rayon::range_inclusive::Iter<u64>, fn(u64) -> ProductID>;
+
+    type Item = ProductID;
+
+    fn into_par_iter(self) -> Self::Iter {
+        (self.first.0..=self.last.0).into_par_iter().map(ProductID)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Deref, DerefMut)]
+pub struct ProductID(pub u64);
+
+impl AocParse for ProductID {
+    fn aoc_parse(bytes: &[u8]) -> Result<Self> { Self::from_bytes(bytes) }
+}
+
+impl ProductID {
+    pub fn from_string(value: impl Display) -> Result<Self> {
+        Self::from_bytes(value.to_string().as_bytes())
+    }
+
+    pub fn from_bytes(value: &[u8]) -> Result<Self> {
+        if value.starts_with(b"0") {
+            return Err(AocError::parse(1, 1, "Invalid ID: Shouldn't start with 0"));
+        }
+
+        Ok(Self(parse_int(value)?))
+    }
+
+    /// Number of decimal digits
+    pub const fn digits(&self) -> u32 {
+        match self.0 {
+            0 => 1,
+            id => id.ilog10() + 1,
+        }
+    }
+
+    pub fn is_valid_part_1(&self) -> bool {
+        let text = self.to_string();
+        let len = text.len();
+
+        if len == 0 {
+            return false;
+        }
+
+        if len.is_multiple_of(2) {
+            let (left, right) = text.split_at(len / 2);
+
+            if right.contains(left) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    pub fn is_valid_part_2(&self) -> bool {
+        let text = self.to_string();
+        let len = text.len();
+
+        if len == 0 {
+            return false;
+        }
+
+        return !(1..len).into_par_iter().any(|i| {
+            let left = text.split_at(i).0;
+
+            if !len.is_multiple_of(i) {
+                return false
+            }
+
+            let n_repeat = len.div_euclid(i);
+            let left_repeated = left.repeat(n_repeat);
+
+            debug!(
+                "Text {text}; len {len}; i {i}; n_repeat {n_repeat}; left {left}; left_repeated {left_repeated}",
+            );
+
+            return left_repeated == text;
+        });
+    }
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Answer = u128;
+    type Parsed<'i> = Vec<ProductIDRange>;
+
+    /// Comma separated ranges, possibly wrapped over several lines
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>> {
+        Vec::parse_separated(text_input.as_bytes(), b",")
+    }
+
+    /// Sum of invalid ProductIDs (digit repeated twice)
+    #[instrument(level = "debug", skip_all, fields(ranges = product_id_ranges.len()))]
+    fn part_1(product_id_ranges: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(product_id_ranges
+            .iter()
+            .map(ProductIDRange::invalid_sum_part_1)
+            .sum())
+    }
+
+    /// Sum of invalid ProductIDs (digit repeated atleast twice)
+    #[instrument(level = "debug", skip_all, fields(ranges = product_id_ranges.len()))]
+    fn part_2(product_id_ranges: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(product_id_ranges
+            .iter()
+            .map(ProductIDRange::invalid_sum_part_2)
+            .sum())
+    }
+}
+
+/// Brute force over every ID, kept as a reference for [`ProductIDRange::invalid_sum_part_1`]
+/// and [`ProductIDRange::invalid_sum_part_2`]
+#[instrument(level = "debug", skip_all, fields(ranges = product_id_ranges.len()))]
+pub fn sum_invalid(
+    product_id_ranges: &[ProductIDRange],
+    is_valid: impl Fn(&ProductID) -> bool + Sync,
+) -> u64 {
+    // Rayon workers don't inherit the current span, the range spans are attached to it explicitly
+    let parent = Span::current();
+
+    product_id_ranges
+        .par_iter()
+        .map(|product_id_range| {
+            let _span = debug_span!(
+                parent: &parent,
+                "range",
+                first = product_id_range.first.0,
+                last = product_id_range.last.0
+            )
+            .entered();
+
+            product_id_range
+                .clone()
+                .into_par_iter()
+                .filter(|product_id| !is_valid(product_id))
+                .map(|product_id| *product_id)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_id_part_1() {
+        assert!(!ProductID::from_string("55").unwrap().is_valid_part_1());
+        assert!(!ProductID::from_string("6464").unwrap().is_valid_part_1());
+        assert!(!ProductID::from_string("123123").unwrap().is_valid_part_1());
+        assert!(ProductID::from_string("101").unwrap().is_valid_part_1());
+    }
+
+    #[test]
+    fn product_id_part_2() {
+        assert!(
+            !ProductID::from_string("12341234")
+                .unwrap()
+                .is_valid_part_2()
+        );
+        assert!(
+            !ProductID::from_string("123123123")
+                .unwrap()
+                .is_valid_part_2()
+        );
+        assert!(
+            !ProductID::from_string("1212121212")
+                .unwrap()
+                .is_valid_part_2()
+        );
+        assert!(ProductID::from_string("1231234").unwrap().is_valid_part_2());
+    }
+
+    #[test]
+    fn product_id_leading_zero() {
+        assert!(ProductID::from_string("0101").is_err());
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force() -> Result {
+        let mut ranges = Day02::parse(INPUT_TEST)?;
+        ranges.extend(
+            [
+                (1, 9),
+                (1, 99_999),
+                (95, 1_000_100),
+                (999_000, 1_001_001),
+                (10, 10),
+            ]
+            .map(|(first, last)| ProductIDRange {
+                first: ProductID(first),
+                last: ProductID(last),
+            }),
+        );
+
+        for range in ranges {
+            let ranges = std::slice::from_ref(&range);
+
+            assert_eq!(
+                range.invalid_sum_part_1(),
+                sum_invalid(ranges, ProductID::is_valid_part_1) as u128,
+                "{range:?}"
+            );
+            assert_eq!(
+                range.invalid_sum_part_2(),
+                sum_invalid(ranges, ProductID::is_valid_part_2) as u128,
+                "{range:?}"
+            );
+        }
+
+        return Ok(());
+    }
+
+    #[test]
+    fn closed_form_full_range() {
+        let range = |first, last| ProductIDRange {
+            first: ProductID(first),
+            last: ProductID(last),
+        };
+        let split = 4_294_967_296_u64;
+
+        let full = range(1, u64::MAX);
+        let (low, high) = (range(1, split), range(split + 1, u64::MAX));
+
+        assert_eq!(
+            full.invalid_sum_part_1(),
+            low.invalid_sum_part_1() + high.invalid_sum_part_1()
+        );
+        assert_eq!(
+            full.invalid_sum_part_2(),
+            low.invalid_sum_part_2() + high.invalid_sum_part_2()
+        );
+        assert!(full.invalid_sum_part_2() > full.invalid_sum_part_1());
+        assert_eq!(range(u64::MAX, u64::MAX).invalid_sum_part_2(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Day02::parse("11-22,95115"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 7,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day02::parse("11-22,\n95-0115"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn example() -> Result {
+        let Solution { part_1, part_2 } = Day02::solve(INPUT_TEST)?;
+        assert_eq!(part_1, 1227775554);
+        assert_eq!(part_2, 4174379265);
+
+        return Ok(());
+    }
+}
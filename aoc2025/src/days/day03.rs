@@ -0,0 +1,139 @@
+use crate::{embedded_input, prelude::*};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub const INPUT_TEST: &str = include_str!("../../inputs/2025_03_input_test.txt");
+pub const INPUT: Option<&str> = embedded_input!("2025_03_input.txt");
+
+/// Batteries turned on in each bank in part 1
+pub const PART_1_BATTERIES: usize = 2;
+/// Batteries turned on in each bank in part 2
+pub const PART_2_BATTERIES: usize = 12;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Answer = u64;
+    /// Digit values of every bank
+    type Parsed<'i> = Vec<Vec<u8>>;
+
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>> {
+        let bytes = text_input.as_bytes();
+
+        lines(bytes)
+            .map(|(offset, bank)| {
+                return bank
+                    .iter()
+                    .enumerate()
+                    .map(|(column, battery)| {
+                        digit(*battery).ok_or_else(|| {
+                            let error = AocError::parse(
+                                1,
+                                column + 1,
+                                format!("Battery `{}` should be a digit", battery.escape_ascii()),
+                            );
+
+                            return relocate(error, bytes, offset);
+                        })
+                    })
+                    .collect();
+            })
+            .collect()
+    }
+
+    fn part_1(banks: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        banks
+            .par_iter()
+            .map(|bank| max_joltage(bank, PART_1_BATTERIES))
+            .sum()
+    }
+
+    fn part_2(banks: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        banks
+            .par_iter()
+            .map(|bank| max_joltage(bank, PART_2_BATTERIES))
+            .sum()
+    }
+}
+
+/// Largest joltage of a bank with `batteries` turned on, see [`max_subsequence_number`].
+/// Fails when the bank has fewer than `batteries` batteries, or when the joltage overflows
+/// a `u64`
+pub fn max_joltage(bank: &[u8], batteries: usize) -> Result<u64> {
+    let Some(Subsequence { value, indices }) = max_subsequence_number(bank, batteries) else {
+        return Err(format!(
+            "Battery bank of {} batteries can't have {batteries} turned on",
+            bank.len()
+        )
+        .into());
+    };
+
+    debug!("joltage: {value}; batteries turned on: {indices:?}");
+
+    return u64::try_from(value).map_err(|_| {
+        format!("Joltage {value} of {batteries} batteries doesn't fit in a u64").into()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bank(text: &str) -> Vec<u8> { Day03::parse(text).unwrap().remove(0) }
+
+    #[test]
+    fn battery_joltage_2() -> Result {
+        assert_eq!(max_joltage(&bank("987654321111111"), 2)?, 98);
+        assert_eq!(max_joltage(&bank("811111111111119"), 2)?, 89);
+        assert_eq!(max_joltage(&bank("234234234234278"), 2)?, 78);
+        assert_eq!(max_joltage(&bank("818181911112111"), 2)?, 92);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn battery_joltage_12() -> Result {
+        assert_eq!(max_joltage(&bank("987654321111111"), 12)?, 987654321111);
+        assert_eq!(max_joltage(&bank("811111111111119"), 12)?, 811111111119);
+        assert_eq!(max_joltage(&bank("234234234234278"), 12)?, 434234234278);
+        assert_eq!(max_joltage(&bank("818181911112111"), 12)?, 888911112111);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Day03::parse("987654321111111\n8111111a1111119"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 8,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn short_banks() -> Result {
+        let banks = Day03::parse("98765\n43")?;
+
+        assert_eq!(Day03::part_1(&banks)?, 98 + 43);
+        assert!(Day03::part_2(&banks).is_err());
+        assert!(max_joltage(&bank("9"), 2).is_err());
+        assert_eq!(max_joltage(&bank(&"9".repeat(20)), 19)?, 10_u64.pow(19) - 1);
+        assert!(max_joltage(&bank(&"9".repeat(20)), 20).is_err());
+
+        return Ok(());
+    }
+
+    #[test]
+    fn example() -> Result {
+        let Solution { part_1, part_2 } = Day03::solve(INPUT_TEST)?;
+        assert_eq!(part_1, 357);
+        assert_eq!(part_2, 3121910778619);
+
+        return Ok(());
+    }
+}
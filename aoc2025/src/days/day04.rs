@@ -0,0 +1,136 @@
+use crate::{embedded_input, prelude::*};
+use std::fmt::Display;
+
+pub const INPUT_TEST: &str = include_str!("../../inputs/2025_04_input_test.txt");
+pub const INPUT: Option<&str> = embedded_input!("2025_04_input.txt");
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Answer = u64;
+    type Parsed<'i> = Grid<StuffKind>;
+
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>> {
+        Grid::parse(text_input, StuffKind::from_char)
+    }
+
+    /// Sum of (fewer than four adjacent) accessible papers rolls
+    fn part_1(grid: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(Automaton::new(grid.clone(), remove_accessible).step().len() as _)
+    }
+
+    /// Sum of (fewer than four adjacent) accessible papers rolls recursively until no more
+    fn part_2(grid: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(Automaton::new(grid.clone(), remove_accessible)
+            .run_async()
+            .total() as _)
+    }
+
+    /// Removal waves, the rolls taken at each step highlighted
+    fn visualize(grid: &Self::Parsed<'_>) -> Option<Vec<Frame>> {
+        let mut frames = vec![Frame::capture(0, grid, &[])];
+
+        Automaton::new(grid.clone(), remove_accessible).run_sync_observed(|grid, changed| {
+            if !changed.is_empty() {
+                frames.push(Frame::capture(frames.len(), grid, changed));
+            }
+        });
+
+        return Some(frames);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum StuffKind {
+    Empty,
+    PaperRoll,
+}
+
+/// Same glyph as [`Render::glyph`], the only place it is spelled out
+impl Display for StuffKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+impl Render for StuffKind {
+    fn glyph(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::PaperRoll => '@',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Self::Empty => [40, 42, 54],
+            Self::PaperRoll => [241, 250, 238],
+        }
+    }
+}
+
+pub fn count_adjacent(grid: &Grid<StuffKind>, pos: Pos) -> u8 {
+    let counter = grid
+        .neighbors_8(pos)
+        .filter(|neighbor| grid[*neighbor] == StuffKind::PaperRoll)
+        .count() as _;
+
+    debug!("pos: {pos:?}; counter: {counter}");
+
+    counter
+}
+
+/// [`Automaton`] rule taking the paper rolls with fewer than four adjacent rolls
+pub fn remove_accessible(grid: &Grid<StuffKind>, pos: Pos) -> Option<StuffKind> {
+    (grid[pos] == StuffKind::PaperRoll && count_adjacent(grid, pos) < 4).then_some(StuffKind::Empty)
+}
+
+impl StuffKind {
+    pub const ALL: [Self; 2] = [Self::Empty, Self::PaperRoll];
+
+    pub fn from_char(char: char) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.glyph() == char)
+            .ok_or_else(|| format!("Unknown grid character `{char}`").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_adjacent() -> Result {
+        let grid = Day04::parse(INPUT_TEST)?;
+        assert_eq!(super::count_adjacent(&grid, (0, 0)), 2);
+        assert_eq!(super::count_adjacent(&grid, (1, 1)), 6);
+        assert_eq!(super::count_adjacent(&grid, (4, 4)), 8);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Day04::parse("..@\n.#@"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn example() -> Result {
+        let Solution { part_1, part_2 } = Day04::solve(INPUT_TEST)?;
+        assert_eq!(part_1, 13);
+        assert_eq!(part_2, 43);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{embedded_input, prelude::*};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub const INPUT_TEST: &str = include_str!("../../inputs/2025_05_input_test.txt");
+pub const INPUT: Option<&str> = embedded_input!("2025_05_input.txt");
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Answer = u64;
+    type Parsed<'i> = Database;
+
+    fn parse(text_input: &str) -> Result<Self::Parsed<'_>> {
+        Database::aoc_parse(text_input.as_bytes())
+    }
+
+    /// Number of available ingredients that are fresh
+    fn part_1(database: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(database.count_fresh_ingredients() as _)
+    }
+
+    /// Number of ingredient IDs covered by the fresh ranges
+    fn part_2(database: &Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(database.freshs.covered() as _)
+    }
+}
+
+/// Fresh ranges, an empty line, then the available ingredients
+#[derive(Clone, PartialEq, Eq, Hash, Debug, AocParse)]
+#[aoc(blocks)]
+pub struct Database {
+    freshs: RangeSet<usize>,
+    ingredients: Vec<usize>,
+}
+
+impl Database {
+    pub fn count_fresh_ingredients(&self) -> usize {
+        return self
+            .ingredients
+            .par_iter()
+            .filter(|ingredient| self.freshs.contains(**ingredient))
+            .count();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            Day05::parse("3-5\n10-14"),
+            Err(AocError::Parse {
+                line: 3,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day05::parse("3-5\n1014\n\n1"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day05::parse("3-5\n10-x\n\n1"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day05::parse("3-5\n\n1\nfive"),
+            Err(AocError::Parse {
+                line: 4,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn example() -> Result {
+        let Solution { part_1, part_2 } = Day05::solve(INPUT_TEST)?;
+        assert_eq!(part_1, 3);
+        assert_eq!(part_2, 14);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,15 @@
+use aoc_core::runner::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(day01::INPUT),
+    Day::new::<day02::Day02>(day02::INPUT),
+    Day::new::<day03::Day03>(day03::INPUT),
+    Day::new::<day04::Day04>(day04::INPUT),
+    Day::new::<day05::Day05>(day05::INPUT),
+];
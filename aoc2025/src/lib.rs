@@ -1,3 +1,3 @@
-pub mod error;
-pub mod prelude;
-pub mod tracing;
+pub use aoc_core::{embedded_input, prelude};
+
+pub mod days;
@@ -1 +0,0 @@
-pub use crate::{error::*, tracing::*};